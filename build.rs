@@ -0,0 +1,50 @@
+//! Generates the solution registry used by the `registry` feature.
+//! Every `src/bin/YYYY_NN.rs` is included as a module of the library so that days can be run in-process.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let bin_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/bin");
+
+    let mut puzzles: Vec<String> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let name = entry.file_name().into_string().ok()?;
+                    let puzzle = name.strip_suffix(".rs")?;
+                    let (year, day) = puzzle.split_once('_')?;
+                    (year.len() == 4
+                        && day.len() == 2
+                        && (year.chars().chain(day.chars())).all(|c| c.is_ascii_digit()))
+                    .then(|| puzzle.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    puzzles.sort_unstable();
+
+    let mut registry = String::new();
+
+    for puzzle in &puzzles {
+        let path = bin_dir.join(format!("{puzzle}.rs"));
+        registry.push_str(&format!(
+            "#[allow(dead_code)]\n#[path = {:?}]\npub mod day_{puzzle};\n\n",
+            path.display().to_string()
+        ));
+    }
+
+    registry.push_str(&format!(
+        "/// Every solution in `src/bin`, ordered by year and day.\npub const SOLUTIONS: &[crate::template::registry::DaySolution] = &[{}];\n",
+        puzzles
+            .iter()
+            .map(|puzzle| format!("day_{puzzle}::SOLUTION"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("registry.rs");
+    fs::write(out_path, registry).unwrap();
+}
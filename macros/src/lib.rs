@@ -0,0 +1,814 @@
+//! Attribute macros for declaring the parts of a solution, re-exported as `advent_of_code::aoc`.
+//!
+//! ```ignore
+//! #[aoc::solution(2025, 5)]
+//! mod solution {
+//!     #[aoc::parse]
+//!     pub fn parse(input: &str) -> Kitchen { ... }
+//!
+//!     #[aoc::part(1)]
+//!     pub fn fresh_ingredients(kitchen: &Kitchen) -> Option<usize> { ... }
+//!
+//!     #[aoc::variant(1, "sorted")]
+//!     pub fn fresh_ingredients_sorted(kitchen: &Kitchen) -> Option<usize> { ... }
+//! }
+//! ```
+//!
+//! The module expands to the same wiring as `advent_of_code::solution!`.
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::visit::Visit;
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    Attribute, Error, FnArg, Ident, Item, ItemFn, ItemMod, Lifetime, LitInt, LitStr, ReturnType,
+    Token, Type, TypeImplTrait, TypeReference, Visibility, parse_macro_input, parse_quote,
+};
+
+/// Declares the solution of a day in an inline module, e.g. `#[aoc::solution(2025, 5)] mod solution { ... }`.
+///
+/// Functions of the module are declared as parts with `#[aoc::part(1)]` and `#[aoc::part(2)]`,
+/// as the parse phase with `#[aoc::parse]`, as a solution of both parts with `#[aoc::combined]`,
+/// or as a named variant of a part with `#[aoc::variant(1, "name")]`.
+#[proc_macro_attribute]
+pub fn solution(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args with Punctuated::<LitInt, Token![,]>::parse_terminated);
+    let module = parse_macro_input!(item as ItemMod);
+
+    expand(&args, module)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Declares the parse phase of a solution, see [`macro@solution`].
+#[proc_macro_attribute]
+pub fn parse(_args: TokenStream, item: TokenStream) -> TokenStream {
+    misplaced("parse", item)
+}
+
+/// Declares a part of a solution, see [`macro@solution`].
+#[proc_macro_attribute]
+pub fn part(_args: TokenStream, item: TokenStream) -> TokenStream {
+    misplaced("part", item)
+}
+
+/// Declares a named variant of a part, see [`macro@solution`].
+#[proc_macro_attribute]
+pub fn variant(_args: TokenStream, item: TokenStream) -> TokenStream {
+    misplaced("variant", item)
+}
+
+/// Declares a function that solves both parts in one pass, see [`macro@solution`].
+#[proc_macro_attribute]
+pub fn combined(_args: TokenStream, item: TokenStream) -> TokenStream {
+    misplaced("combined", item)
+}
+
+/// The attributes of a solution's functions are consumed by [`macro@solution`], they are only expanded on their own if misplaced.
+fn misplaced(name: &str, item: TokenStream) -> TokenStream {
+    let item = TokenStream2::from(item);
+    let error = Error::new(
+        Span::call_site(),
+        format!(
+            "`#[aoc::{name}]` must be used on a function in a module annotated with `#[aoc::solution(year, day)]`"
+        ),
+    )
+    .into_compile_error();
+
+    quote!(#error #item).into()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Role {
+    Parse,
+    Part(u8),
+    Variant(u8, String),
+    Combined,
+}
+
+/// A function of the module along with its role.
+struct Declared {
+    role: Role,
+    func: Ident,
+    attr: Attribute,
+    /// Answer types of a part or combined function, see [`answer_types`].
+    answers: Option<Vec<Type>>,
+}
+
+struct VariantArgs {
+    part: LitInt,
+    name: LitStr,
+}
+
+impl Parse for VariantArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let part = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let name = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { part, name })
+    }
+}
+
+/// Reads the role of a function from one of its attributes, e.g. `#[aoc::part(1)]` or `#[part(1)]`.
+/// Returns `None` for attributes that do not belong to this crate.
+fn role_of(attr: &Attribute) -> syn::Result<Option<Role>> {
+    let segments: Vec<String> = attr
+        .path()
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+
+    let name = match segments.as_slice() {
+        [name] => name,
+        [.., namespace, name] if namespace == "aoc" => name,
+        _ => return Ok(None),
+    };
+
+    let role = match name.as_str() {
+        "parse" => {
+            attr.meta.require_path_only()?;
+            Role::Parse
+        }
+        "combined" => {
+            attr.meta.require_path_only()?;
+            Role::Combined
+        }
+        "part" => Role::Part(parse_part(&attr.parse_args()?)?),
+        "variant" => {
+            let args: VariantArgs = attr.parse_args()?;
+            Role::Variant(parse_part(&args.part)?, args.name.value())
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(role))
+}
+
+fn parse_part(lit: &LitInt) -> syn::Result<u8> {
+    match lit.base10_parse::<u8>() {
+        Ok(part @ (1 | 2)) => Ok(part),
+        _ => Err(Error::new_spanned(lit, "expected part 1 or 2")),
+    }
+}
+
+/// Checks that the runner can call `func` in its `role`.
+fn check_signature(func: &ItemFn, role: &Role, has_parse: bool) -> syn::Result<()> {
+    let sig = &func.sig;
+    let name = &sig.ident;
+
+    if let Some(asyncness) = sig.asyncness {
+        return Err(Error::new_spanned(
+            asyncness,
+            format!("`{name}` cannot be async, the runner calls it synchronously"),
+        ));
+    }
+
+    if let Some(param) = sig.generics.type_params().next() {
+        return Err(Error::new_spanned(
+            param,
+            format!("`{name}` cannot have type parameters, the runner cannot infer them"),
+        ));
+    }
+
+    if let Some(param) = sig.generics.const_params().next() {
+        return Err(Error::new_spanned(
+            param,
+            format!("`{name}` cannot have const parameters, the runner cannot infer them"),
+        ));
+    }
+
+    let input = match (sig.inputs.len(), sig.inputs.first()) {
+        (1, Some(FnArg::Typed(input))) => input,
+        _ => {
+            let expected = match role {
+                Role::Parse => "the input, e.g. `input: &str`",
+                _ if has_parse => "a reference to the parsed input",
+                _ => "the input, e.g. `input: &str`",
+            };
+            return Err(Error::new_spanned(
+                &sig.inputs,
+                format!("`{name}` must take exactly one argument, {expected}"),
+            ));
+        }
+    };
+
+    let takes_parsed = has_parse && *role != Role::Parse;
+
+    match &*input.ty {
+        Type::Reference(TypeReference { elem, .. }) if takes_parsed || is_str(elem) => {}
+        ty => {
+            let expected = if takes_parsed {
+                "a reference to the output of the parse function"
+            } else {
+                "`&str`"
+            };
+            return Err(Error::new_spanned(
+                ty,
+                format!("`{name}` must take {expected}"),
+            ));
+        }
+    }
+
+    let ReturnType::Type(_, output) = &sig.output else {
+        let expected = match role {
+            Role::Parse => "the parsed input",
+            Role::Combined => "the answers of both parts, e.g. `(Option<u64>, Option<u64>)`",
+            _ => "its answer, e.g. `Option<u64>`",
+        };
+        return Err(Error::new_spanned(
+            sig,
+            format!("`{name}` must return {expected}"),
+        ));
+    };
+
+    let answers: Vec<&Type> = match (role, &**output) {
+        (Role::Parse, _) => vec![],
+        (Role::Combined, Type::Tuple(tuple)) if tuple.elems.len() == 2 => {
+            tuple.elems.iter().collect()
+        }
+        (Role::Combined, _) => {
+            return Err(Error::new_spanned(
+                output,
+                format!(
+                    "`{name}` must return the answers of both parts as a tuple, e.g. `(Option<u64>, Option<u64>)`"
+                ),
+            ));
+        }
+        _ => vec![output],
+    };
+
+    if let Some(answer) = answers.into_iter().find(|answer| !is_answer(answer)) {
+        return Err(Error::new_spanned(
+            answer,
+            format!(
+                "`{name}` must return an answer as `Option<T>` or `Result<T, E>`, e.g. `Option<u64>`"
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Whether `ty` is an `Option` or `Result`, the outputs the runner accepts as the answer of a part.
+fn is_answer(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option" || segment.ident == "Result"),
+        Type::Paren(paren) => is_answer(&paren.elem),
+        Type::Group(group) => is_answer(&group.elem),
+        _ => false,
+    }
+}
+
+fn is_str(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("str"))
+}
+
+/// Rewrites the lifetimes of the parse function's output to `'a`, the lifetime of the input in `Solution::Parsed<'a>`.
+struct ParsedLifetimes<'f> {
+    /// Lifetime parameters of the parse function.
+    named: Vec<&'f Ident>,
+}
+
+impl VisitMut for ParsedLifetimes<'_> {
+    fn visit_type_reference_mut(&mut self, reference: &mut TypeReference) {
+        if reference.lifetime.is_none() {
+            reference.lifetime = Some(Lifetime::new("'a", Span::call_site()));
+        }
+        visit_mut::visit_type_reference_mut(self, reference);
+    }
+
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime.ident == "_" || self.named.contains(&&lifetime.ident) {
+            *lifetime = Lifetime::new("'a", Span::call_site());
+        }
+    }
+}
+
+/// Whether a type can be named outside of the function returning it,
+/// i.e. it does not borrow from the function's argument and is not an `impl Trait`.
+struct Nameable(bool);
+
+impl Visit<'_> for Nameable {
+    fn visit_type_reference(&mut self, _: &TypeReference) {
+        self.0 = false;
+    }
+
+    fn visit_type_impl_trait(&mut self, _: &TypeImplTrait) {
+        self.0 = false;
+    }
+
+    fn visit_lifetime(&mut self, _: &Lifetime) {
+        self.0 = false;
+    }
+}
+
+/// The answer types of a part or combined function, if they can be named by the `Solution` implementation.
+fn answer_types(func: &ItemFn, role: &Role) -> Option<Vec<Type>> {
+    let ReturnType::Type(_, output) = &func.sig.output else {
+        return None;
+    };
+
+    let mut nameable = Nameable(true);
+    nameable.visit_type(output);
+    if !nameable.0 {
+        return None;
+    }
+
+    match (role, &**output) {
+        (Role::Part(_), ty) => Some(vec![ty.clone()]),
+        (Role::Combined, Type::Tuple(tuple)) => Some(tuple.elems.iter().cloned().collect()),
+        _ => None,
+    }
+}
+
+/// Combines `errors` into a single error that reports all of them.
+fn combine(errors: Vec<Error>) -> syn::Result<()> {
+    let mut errors = errors.into_iter();
+    match errors.next() {
+        Some(mut error) => {
+            errors.for_each(|e| error.combine(e));
+            Err(error)
+        }
+        None => Ok(()),
+    }
+}
+
+fn expand(args: &Punctuated<LitInt, Token![,]>, mut module: ItemMod) -> syn::Result<TokenStream2> {
+    let (year, day) = match (args.len(), args.first(), args.last()) {
+        (2, Some(year), Some(day)) => (year, day),
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "expected the year and day of the puzzle, e.g. `#[aoc::solution(2025, 5)]`",
+            ));
+        }
+    };
+
+    let mod_ident = module.ident.clone();
+    let Some((_, items)) = &mut module.content else {
+        return Err(Error::new_spanned(
+            &module,
+            "`#[aoc::solution]` must be used on an inline module, e.g. `mod solution { ... }`",
+        ));
+    };
+
+    let mut errors = vec![];
+    let mut declared: Vec<Declared> = vec![];
+
+    // NOTE: the roles are collected before checking signatures, which depend on whether the input is parsed.
+    let mut funcs: Vec<(&mut ItemFn, Role, Attribute)> = vec![];
+
+    for item in items.iter_mut() {
+        let Item::Fn(func) = item else {
+            continue;
+        };
+
+        let mut roles = vec![];
+        let mut attrs = vec![];
+
+        for attr in func.attrs.drain(..) {
+            match role_of(&attr) {
+                Ok(Some(role)) => roles.push((role, attr)),
+                Ok(None) => attrs.push(attr),
+                Err(e) => errors.push(e),
+            }
+        }
+        func.attrs = attrs;
+
+        if let Some((_, attr)) = roles.get(1) {
+            errors.push(Error::new_spanned(
+                attr,
+                format!(
+                    "`{}` can only have one of `#[aoc::parse]`, `#[aoc::part]`, `#[aoc::variant]` or `#[aoc::combined]`",
+                    func.sig.ident
+                ),
+            ));
+            continue;
+        }
+
+        if let Some((role, attr)) = roles.pop() {
+            funcs.push((func, role, attr));
+        }
+    }
+
+    let has_parse = funcs.iter().any(|(_, role, _)| *role == Role::Parse);
+    let mut parsed = None;
+
+    for (func, role, attr) in funcs {
+        if let Err(e) = check_signature(func, &role, has_parse) {
+            errors.push(e);
+            // NOTE: the role is still declared, so that e.g. the module is not reported as declaring no parts.
+            declared.push(Declared {
+                answers: None,
+                role,
+                func: func.sig.ident.clone(),
+                attr,
+            });
+            continue;
+        }
+
+        // NOTE: the functions are called from outside of the module.
+        if matches!(func.vis, Visibility::Inherited) {
+            func.vis = parse_quote!(pub);
+        }
+
+        if role == Role::Parse
+            && parsed.is_none()
+            && let ReturnType::Type(_, output) = &func.sig.output
+        {
+            let mut output = (**output).clone();
+            ParsedLifetimes {
+                named: func
+                    .sig
+                    .generics
+                    .lifetimes()
+                    .map(|l| &l.lifetime.ident)
+                    .collect(),
+            }
+            .visit_type_mut(&mut output);
+            parsed = Some(output);
+        }
+
+        declared.push(Declared {
+            answers: answer_types(func, &role),
+            role,
+            func: func.sig.ident.clone(),
+            attr,
+        });
+    }
+
+    errors.extend(check_declarations(&mod_ident, &declared));
+
+    // NOTE: the module is kept along with the errors, so that its code is still checked without follow-up errors.
+    if let Err(error) = combine(errors) {
+        let error = error.into_compile_error();
+        return Ok(quote! {
+            #error
+            #module
+            fn main() {}
+        });
+    }
+
+    let lifetime = Lifetime::new("'a", Span::call_site());
+    if let Some(parsed) = parsed {
+        items.push(parse_quote! {
+            #[doc(hidden)]
+            pub type __Parsed<#lifetime> = #parsed;
+        });
+    }
+
+    let find = |role: &Role| declared.iter().find(|d| d.role == *role);
+
+    // NOTE: the answer types are aliased in the module, where the types they refer to are in scope.
+    let mut answer_aliases = vec![];
+    let mut alias_answers = |d: &Declared, parts: &[u8]| {
+        let answers = d.answers.as_ref()?;
+        let aliases: Vec<Ident> = parts
+            .iter()
+            .zip(answers)
+            .map(|(part, answer)| {
+                let alias = Ident::new(&format!("__Part{part}"), Span::call_site());
+                answer_aliases.push(parse_quote! {
+                    #[doc(hidden)]
+                    pub type #alias = #answer;
+                });
+                alias
+            })
+            .collect();
+        Some(quote!(=> #(#mod_ident::#aliases),*))
+    };
+
+    let parse = find(&Role::Parse).map(|d| {
+        let func = &d.func;
+        quote!(#mod_ident::#func => #mod_ident::__Parsed<#lifetime>)
+    });
+    let combined = find(&Role::Combined).map(|d| {
+        let func = &d.func;
+        let answers = alias_answers(d, &[1, 2]);
+        quote!(#mod_ident::#func #answers)
+    });
+
+    let mut missing = vec![];
+    let mut parts = vec![];
+    for part in [1, 2] {
+        let lit = LitInt::new(&part.to_string(), Span::call_site());
+        match find(&Role::Part(part)) {
+            Some(d) => {
+                let func = &d.func;
+                let answer = alias_answers(d, &[part]);
+                parts.push(quote!([#mod_ident::#func, #lit #answer]));
+            }
+            None if combined.is_none() => missing.push(lit),
+            None => {}
+        }
+    }
+    items.extend(answer_aliases);
+
+    let variants = declared.iter().filter_map(|d| match &d.role {
+        Role::Variant(part, name) => {
+            let part = LitInt::new(&part.to_string(), Span::call_site());
+            let func = &d.func;
+            Some(quote!((#part, #name, #mod_ident::#func)))
+        }
+        _ => None,
+    });
+
+    Ok(quote! {
+        #module
+
+        advent_of_code::solution!(@impl #year, #day, [#parse], [#combined], [#(#missing)*], #(#parts)*; #(#variants),*);
+    })
+}
+
+/// Checks that the declared functions form a solution, e.g. that no part is declared twice.
+fn check_declarations(mod_ident: &Ident, declared: &[Declared]) -> Vec<Error> {
+    let mut errors = vec![];
+
+    for (i, d) in declared.iter().enumerate() {
+        let first = declared[..i]
+            .iter()
+            .find(|other| match (&other.role, &d.role) {
+                (Role::Variant(a, x), Role::Variant(b, y)) => a == b && x == y,
+                (a, b) => a == b,
+            });
+
+        if let Some(first) = first {
+            let what = match &d.role {
+                Role::Parse => "the parse function".to_string(),
+                Role::Part(part) => format!("part {part}"),
+                Role::Variant(part, name) => format!("variant \"{name}\" of part {part}"),
+                Role::Combined => "the combined solution".to_string(),
+            };
+            errors.push(Error::new_spanned(
+                &d.attr,
+                format!(
+                    "{what} is declared twice, `{}` already declares it",
+                    first.func
+                ),
+            ));
+            continue;
+        }
+
+        let has = |role: &Role| declared.iter().any(|other| other.role == *role);
+
+        match &d.role {
+            Role::Part(part) if has(&Role::Combined) => errors.push(Error::new_spanned(
+                &d.attr,
+                format!("part {part} is already solved by the function marked `#[aoc::combined]`"),
+            )),
+            Role::Variant(_, _) if has(&Role::Combined) => errors.push(Error::new_spanned(
+                &d.attr,
+                "combined solutions cannot have variants",
+            )),
+            Role::Variant(part, _) if !has(&Role::Part(*part)) => errors.push(Error::new_spanned(
+                &d.attr,
+                format!("`{}` is a variant of part {part}, which is not declared with `#[aoc::part({part})]`", d.func),
+            )),
+            _ => {}
+        }
+    }
+
+    let has_parts = declared
+        .iter()
+        .any(|d| matches!(d.role, Role::Part(_) | Role::Combined));
+
+    if !has_parts && errors.is_empty() {
+        errors.push(Error::new_spanned(
+            mod_ident,
+            format!("`{mod_ident}` declares no parts, annotate a function with `#[aoc::part(1)]` or `#[aoc::part(2)]`"),
+        ));
+    }
+
+    errors
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+    use syn::visit_mut::VisitMut;
+    use syn::{ItemFn, ItemMod, Type, parse_quote};
+
+    use super::{ParsedLifetimes, Role, expand, role_of};
+
+    fn expand_module(module: ItemMod) -> String {
+        expand(&parse_quote!(2025, 5), module).unwrap().to_string()
+    }
+
+    #[test]
+    fn reads_roles() {
+        let func: ItemFn = parse_quote! {
+            #[aoc::part(2)]
+            #[advent_of_code::aoc::variant(1, "bytes")]
+            #[parse]
+            #[inline]
+            fn f() {}
+        };
+        let roles: Vec<_> = func
+            .attrs
+            .iter()
+            .map(|attr| role_of(attr).unwrap())
+            .collect();
+        assert_eq!(
+            roles,
+            [
+                Some(Role::Part(2)),
+                Some(Role::Variant(1, "bytes".into())),
+                Some(Role::Parse),
+                None
+            ]
+        );
+
+        let func: ItemFn = parse_quote!(
+            #[aoc::part(3)]
+            fn f() {}
+        );
+        assert!(role_of(&func.attrs[0]).is_err());
+    }
+
+    #[test]
+    fn rewrites_parsed_lifetimes() {
+        let mut ty: Type = parse_quote!(Vec<(&str, Grid<'_>, Line<'x>)>);
+        let x = parse_quote!(x);
+        ParsedLifetimes { named: vec![&x] }.visit_type_mut(&mut ty);
+        assert_eq!(
+            ty.to_token_stream().to_string(),
+            "Vec < (& 'a str , Grid < 'a > , Line < 'a >) >"
+        );
+    }
+
+    #[test]
+    fn expands_to_the_solution_macro() {
+        let expanded = expand_module(parse_quote! {
+            mod solution {
+                #[aoc::parse]
+                fn parse(input: &str) -> Vec<&str> { input.lines().collect() }
+                #[aoc::part(1)]
+                pub fn count(lines: &Vec<&str>) -> Option<usize> { Some(lines.len()) }
+                #[aoc::variant(1, "fast")]
+                pub fn count_fast(lines: &Vec<&str>) -> Option<usize> { Some(lines.len()) }
+            }
+        });
+
+        assert!(expanded.contains("pub fn parse"));
+        assert!(expanded.contains("pub type __Parsed < 'a > = Vec < & 'a str >"));
+        assert!(expanded.contains("pub type __Part1 = Option < usize >"));
+        assert!(expanded.contains(
+            "advent_of_code :: solution ! (@ impl 2025 , 5 , [solution :: parse => solution :: __Parsed < 'a >] , [] , [2] , \
+            [solution :: count , 1 => solution :: __Part1] ; (1 , \"fast\" , solution :: count_fast))"
+        ));
+    }
+
+    #[test]
+    fn names_answer_types() {
+        let expanded = expand_module(parse_quote! {
+            mod solution {
+                #[aoc::combined]
+                fn solve(input: &str) -> (Option<usize>, Result<u64, String>) { (None, Ok(0)) }
+            }
+        });
+        assert!(expanded.contains("pub type __Part1 = Option < usize >"));
+        assert!(expanded.contains("pub type __Part2 = Result < u64 , String >"));
+        assert!(
+            expanded.contains(
+                "[solution :: solve => solution :: __Part1 , solution :: __Part2] , [] ,"
+            )
+        );
+
+        // NOTE: answers borrowing from the input cannot be named by the `Solution` implementation.
+        let expanded = expand_module(parse_quote! {
+            mod solution {
+                #[aoc::part(2)]
+                fn first(input: &str) -> Option<&str> { input.lines().next() }
+            }
+        });
+        assert!(!expanded.contains("__Part2"));
+        assert!(expanded.contains("[1] , [solution :: first , 2] ;"));
+    }
+
+    #[test]
+    fn rejects_invalid_solutions() {
+        let error = |module: ItemMod| expand_module(module);
+
+        assert!(
+            error(parse_quote!(
+                mod solution {
+                    #[aoc::part(1)]
+                    fn a(i: &str) -> Option<u8> {
+                        Some(1)
+                    }
+                    #[aoc::part(1)]
+                    fn b(i: &str) -> Option<u8> {
+                        Some(1)
+                    }
+                }
+            ))
+            .contains("part 1 is declared twice, `a` already declares it")
+        );
+        assert!(
+            error(parse_quote!(
+                mod solution {
+                    #[aoc::part(1)]
+                    fn a(i: &str, j: u8) -> u8 {
+                        1
+                    }
+                }
+            ))
+            .contains("must take exactly one argument")
+        );
+        assert!(
+            error(parse_quote!(
+                mod solution {
+                    #[aoc::part(1)]
+                    fn a(i: String) -> u8 {
+                        1
+                    }
+                }
+            ))
+            .contains("must take `&str`")
+        );
+        assert!(
+            error(parse_quote!(
+                mod solution {
+                    #[aoc::part(1)]
+                    fn a(i: &str) {}
+                }
+            ))
+            .contains("must return its answer")
+        );
+        assert!(
+            error(parse_quote!(
+                mod solution {
+                    #[aoc::part(1)]
+                    fn a(i: &str) -> () {}
+                }
+            ))
+            .contains("`a` must return an answer as `Option<T>` or `Result<T, E>`")
+        );
+        assert!(
+            error(parse_quote!(
+                mod solution {
+                    #[aoc::part(2)]
+                    fn a(i: &str) -> usize {
+                        1
+                    }
+                }
+            ))
+            .contains("`a` must return an answer as `Option<T>` or `Result<T, E>`")
+        );
+        assert!(
+            !error(parse_quote!(
+                mod solution {
+                    #[aoc::part(1)]
+                    fn a(i: &str) -> usize {
+                        1
+                    }
+                }
+            ))
+            .contains("declares no parts")
+        );
+        assert!(
+            error(parse_quote!(
+                mod solution {
+                    #[aoc::combined]
+                    fn a(i: &str) -> (Option<usize>, usize) {
+                        (None, 1)
+                    }
+                }
+            ))
+            .contains("`a` must return an answer as `Option<T>` or `Result<T, E>`")
+        );
+        assert!(
+            error(parse_quote!(
+                mod solution {
+                    #[aoc::variant(2, "x")]
+                    fn a(i: &str) -> Option<u8> {
+                        Some(1)
+                    }
+                }
+            ))
+            .contains("which is not declared with `#[aoc::part(2)]`")
+        );
+        assert!(
+            error(parse_quote!(
+                mod solution {
+                    fn a(i: &str) -> u8 {
+                        1
+                    }
+                }
+            ))
+            .contains("declares no parts")
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use std::{fmt::Display, num::ParseIntError, str::FromStr};
+
+advent_of_code::solution!(2025, 1);
+
+#[derive(Debug)]
+enum Rotation {
+    L(usize),
+    R(usize),
+}
+
+impl Rotation {
+    fn rot(&self) -> usize {
+        match self {
+            Self::L(rot) | Self::R(rot) => *rot,
+        }
+    }
+
+    fn delta(&self) -> isize {
+        match *self {
+            Self::L(rot) => -(rot as isize),
+            Self::R(rot) => rot as isize,
+        }
+    }
+
+    /// Returns (pos0_count, remaining delta)
+    fn effective_delta(&self) -> (usize, isize) {
+        let pos0_count = self.delta().unsigned_abs() / 100;
+        let new_rot = (self.rot().rem_euclid(100)) as isize;
+        let effective_delta = match self {
+            Self::L(_) => -new_rot,
+            Self::R(_) => new_rot,
+        };
+        (pos0_count, effective_delta)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseRotationError {
+    Direction(String),
+    Rotation(ParseIntError),
+}
+
+impl Display for ParseRotationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Direction(dir) => write!(f, "unexpected direction {dir:?}"),
+            Self::Rotation(e) => write!(f, "invalid rotation: {e}"),
+        }
+    }
+}
+
+impl FromStr for Rotation {
+    type Err = ParseRotationError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // NOTE: splitting fails if the direction is a multibyte character.
+        let Some((dir, rot)) = s.split_at_checked(1) else {
+            let dir = s.chars().next().map(String::from).unwrap_or_default();
+            return Err(ParseRotationError::Direction(dir));
+        };
+        let rot = || rot.parse::<usize>().map_err(ParseRotationError::Rotation);
+        match dir {
+            "L" => Ok(Self::L(rot()?)),
+            "R" => Ok(Self::R(rot()?)),
+            _ => Err(ParseRotationError::Direction(dir.to_string())),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Dial(isize);
+
+impl Dial {
+    fn new() -> Self {
+        Self(50)
+    }
+
+    /// Returns current pos
+    fn rotate_p1(&mut self, rotation: Rotation) -> isize {
+        let new_pos = (self.0 + rotation.delta()) % 100;
+        self.0 = new_pos;
+        new_pos
+    }
+
+    /// Returns 0 count for this rotation
+    fn rotate_p2(&mut self, rotation: Rotation) -> usize {
+        let (mut pos0_count, effective_delta) = rotation.effective_delta();
+        let new_pos = self.0 + effective_delta;
+
+        if self.0 != 0 && (new_pos <= 0 || new_pos > 99) {
+            pos0_count += 1;
+        }
+
+        self.0 = new_pos.rem_euclid(100);
+        pos0_count
+    }
+}
+
+pub fn part_one(input: &str) -> Result<usize, ParseRotationError> {
+    let rotations = input.lines().map(Rotation::from_str);
+    let mut dial = Dial::new();
+
+    let mut pos0_count = 0;
+
+    for rot in rotations {
+        if dial.rotate_p1(rot?) == 0 {
+            pos0_count += 1;
+        }
+    }
+
+    Ok(pos0_count)
+}
+
+pub fn part_two(input: &str) -> Result<usize, ParseRotationError> {
+    let rotations = input.lines().map(Rotation::from_str);
+    let mut dial = Dial::new();
+
+    let mut pos0_count = 0;
+
+    for rot in rotations {
+        pos0_count += dial.rotate_p2(rot?);
+    }
+
+    Ok(pos0_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(
+            part_one("L68\nX30"),
+            Err(ParseRotationError::Direction("X".into()))
+        );
+        assert!(matches!(
+            part_two("L68\nR"),
+            Err(ParseRotationError::Rotation(_))
+        ));
+        assert_eq!(
+            part_one("Xx"),
+            Err(ParseRotationError::Direction("X".into()))
+        );
+        assert_eq!(
+            part_two("é30"),
+            Err(ParseRotationError::Direction("é".into()))
+        );
+    }
+}
@@ -1,4 +1,11 @@
-advent_of_code::solution!(2);
+advent_of_code::solution!(
+    2025,
+    2,
+    variants: [
+        (1, "ascii", part_one_ascii),
+        (2, "arithmetic", part_two_arithmetic),
+    ]
+);
 
 struct Range(usize, usize);
 
@@ -6,30 +13,16 @@ impl From<&str> for Range {
     fn from(value: &str) -> Self {
         let (min, max) = value.split_once("-").unwrap();
         let max = max.strip_suffix("\n").unwrap_or(max);
-        Self(
-            usize::from_str_radix(min, 10).unwrap(),
-            usize::from_str_radix(max, 10).unwrap(),
-        )
+        Self(min.parse::<usize>().unwrap(), max.parse::<usize>().unwrap())
     }
 }
 
 impl Range {
-    fn sum_invalid_ids_p1(&self) -> usize {
+    fn sum_invalid_ids(&self, is_invalid: impl Fn(&Id) -> bool) -> usize {
         let mut invalid = 0;
         for id in self.0..=self.1 {
             let id = Id(id);
-            if id.is_invalid_p1_alt() {
-                invalid += id.0;
-            }
-        }
-        invalid
-    }
-
-    fn sum_invalid_ids_p2(&self) -> usize {
-        let mut invalid = 0;
-        for id in self.0..=self.1 {
-            let id = Id(id);
-            if id.is_invalid_p2() {
+            if is_invalid(&id) {
                 invalid += id.0;
             }
         }
@@ -41,18 +34,17 @@ impl Range {
 struct Id(usize);
 
 impl Id {
-    #[allow(dead_code)]
     fn is_invalid_p1(&self) -> bool {
         let str_id = self.0.to_string();
         assert!(str_id.is_ascii());
         let str_bytes = str_id.as_bytes();
         let len = str_id.len();
-        if len % 2 != 0 {
+        if !len.is_multiple_of(2) {
             // odd number of digits means no "twice repetition"
             return false;
         }
         let pattern_size = len / 2;
-        return &str_bytes[0..pattern_size] == &str_bytes[pattern_size..];
+        str_bytes[0..pattern_size] == str_bytes[pattern_size..]
     }
 
     /// See [`Self::is_invalid_p2_alt`] documentation.
@@ -65,7 +57,7 @@ impl Id {
         let divider = 10_usize.pow(h_pow10 as u32);
         let lower_half = self.0 % divider;
         let higher_half = self.0 / divider;
-        return lower_half == higher_half;
+        lower_half == higher_half
     }
 
     /// This implementation uses arithmetic to compare
@@ -83,11 +75,10 @@ impl Id {
     ///
     /// Note: This happens to be slower than converting the number into
     /// ascii strings and comparing the ascii bytes.
-    #[allow(dead_code)]
     fn is_invalid_p2_alt(&self) -> bool {
         let n_pow10 = get_next_pow_of_10(self.0 + 1);
         'size_loop: for i_pow10 in 1..=n_pow10 / 2 {
-            if n_pow10 % i_pow10 != 0 {
+            if !n_pow10.is_multiple_of(i_pow10) {
                 // i isn't a divider of n_pow10
                 continue;
             }
@@ -97,7 +88,7 @@ impl Id {
             let lowest_pat = current_number % divider;
             for _ in 1..pattern_amount {
                 // "shift (base10)"
-                current_number = current_number / divider;
+                current_number /= divider;
                 let pat = current_number % divider;
                 if pat != lowest_pat {
                     continue 'size_loop;
@@ -105,7 +96,7 @@ impl Id {
             }
             return true;
         }
-        return false;
+        false
     }
     fn is_invalid_p2(&self) -> bool {
         let str_id = self.0.to_string();
@@ -113,7 +104,7 @@ impl Id {
         let str_bytes = str_id.as_bytes();
         let len = str_id.len();
         'size_loop: for i /* potential repetition size */ in 1..=(len/2) {
-            if len % i != 0 {
+            if !len.is_multiple_of(i) {
                 // i isn't a divider of len
                 continue;
             }
@@ -130,7 +121,7 @@ impl Id {
             // pattern matched
             return true;
         }
-        return false;
+        false
     }
 }
 
@@ -139,42 +130,34 @@ fn get_next_pow_of_10(n: usize) -> usize {
     (n as f64).log10().ceil() as usize
 }
 
+fn sum_invalid_ids(input: &str, is_invalid: impl Fn(&Id) -> bool) -> usize {
+    input
+        .split(",")
+        .map(Range::from)
+        .map(|r| r.sum_invalid_ids(&is_invalid))
+        .sum()
+}
+
 pub fn part_one(input: &str) -> Option<usize> {
-    Some(
-        input
-            .split(",")
-            .map(Range::from)
-            .map(|r| r.sum_invalid_ids_p1())
-            .sum(),
-    )
+    Some(sum_invalid_ids(input, Id::is_invalid_p1_alt))
+}
+
+pub fn part_one_ascii(input: &str) -> Option<usize> {
+    Some(sum_invalid_ids(input, Id::is_invalid_p1))
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
-    Some(
-        input
-            .split(",")
-            .map(Range::from)
-            .map(|r| r.sum_invalid_ids_p2())
-            .sum(),
-    )
+    Some(sum_invalid_ids(input, Id::is_invalid_p2))
+}
+
+pub fn part_two_arithmetic(input: &str) -> Option<usize> {
+    Some(sum_invalid_ids(input, Id::is_invalid_p2_alt))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1227775554));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4174379265));
-    }
-
     #[test]
     fn test_get_pow_of_10() {
         for (n, exp_pow) in [(9, 1), (11, 2), (10, 1), (2, 1), (101, 3), (1, 0)] {
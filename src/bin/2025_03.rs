@@ -1,4 +1,4 @@
-advent_of_code::solution!(3);
+advent_of_code::solution!(2025, 3);
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 struct Battery(u8);
@@ -23,7 +23,7 @@ impl Bank {
         let mut jolt_vals = [0; N];
         let mut cursor = 0;
         let bank_len = self.0.len();
-        for i in 0..N {
+        for (i, jolt_val) in jolt_vals.iter_mut().enumerate() {
             let minimum_keep = N - i - 1;
             let mut cur_max = 0;
             for (index, &b) in self
@@ -41,7 +41,7 @@ impl Bank {
                     break;
                 }
             }
-            jolt_vals[i] = cur_max;
+            *jolt_val = cur_max;
         }
         let mut f = 1;
         let mut total_jolts = 0;
@@ -72,20 +72,3 @@ pub fn part_two(input: &str) -> Option<usize> {
             .sum(),
     )
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(357));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3121910778619));
-    }
-}
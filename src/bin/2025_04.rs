@@ -3,17 +3,17 @@ use std::{
     fmt::{Debug, Write},
 };
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2025, 4);
 
 #[derive(PartialEq, Eq, Clone, Copy)]
 struct PaperRoll;
 
 impl PaperRoll {
-    fn from_char(c: char) -> Option<Self> {
+    fn from_char(c: char) -> Result<Option<Self>, String> {
         match c {
-            '@' => Some(Self),
-            '.' => None,
-            _ => panic!(),
+            '@' => Ok(Some(Self)),
+            '.' => Ok(None),
+            _ => Err(format!("unexpected character {c:?} in grid")),
         }
     }
 }
@@ -28,14 +28,16 @@ struct Grid {
     grid: Vec<Vec<Option<PaperRoll>>>,
 }
 
-impl From<&str> for Grid {
-    fn from(value: &str) -> Self {
-        Self {
+impl TryFrom<&str> for Grid {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(Self {
             grid: value
                 .lines()
                 .map(|l| l.chars().map(PaperRoll::from_char).collect())
-                .collect(),
-        }
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
@@ -83,7 +85,7 @@ impl Grid {
 
     fn verify(&self, x: usize, y: usize) -> bool {
         let mut paper_count = 0;
-        for (nx, ny) in self.get_around_pos(x, y).into_iter().filter_map(|p| p) {
+        for (nx, ny) in self.get_around_pos(x, y).into_iter().flatten() {
             if let Some(PaperRoll) = self.get_tile(nx, ny) {
                 paper_count += 1;
             }
@@ -116,7 +118,7 @@ impl Grid {
                 if self.get_tile(x, y).is_some() && self.verify(x, y) {
                     self.get_around_pos(x, y)
                         .into_iter()
-                        .filter_map(|p| p)
+                        .flatten()
                         .filter(|p| self.get_tile(p.0, p.1).is_some())
                         .for_each(|p| {
                             cache.insert(p);
@@ -138,7 +140,7 @@ impl Grid {
                 if self.verify(x, y) {
                     self.get_around_pos(x, y)
                         .into_iter()
-                        .filter_map(|p| p)
+                        .flatten()
                         .filter(|p| self.get_tile(p.0, p.1).is_some())
                         .for_each(|p| {
                             new_cache.insert(p);
@@ -175,29 +177,12 @@ impl Grid {
 //     }
 // }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let grid = Grid::from(input);
-    Some(grid.count_verified())
-}
-
-pub fn part_two(input: &str) -> Option<usize> {
-    let mut grid = Grid::from(input);
-    Some(grid.remove_possible())
+pub fn part_one(input: &str) -> Result<usize, String> {
+    let grid = Grid::try_from(input)?;
+    Ok(grid.count_verified())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(43));
-    }
+pub fn part_two(input: &str) -> Result<usize, String> {
+    let mut grid = Grid::try_from(input)?;
+    Ok(grid.remove_possible())
 }
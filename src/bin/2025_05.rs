@@ -2,7 +2,7 @@ use std::collections::BTreeSet;
 
 use itertools::Itertools;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(2025, 5, parse: Kitchen::from => Kitchen);
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
 struct IdRange(usize, usize);
@@ -10,10 +10,7 @@ struct IdRange(usize, usize);
 impl From<&str> for IdRange {
     fn from(value: &str) -> Self {
         let (l, r) = value.split_once("-").unwrap();
-        Self(
-            usize::from_str_radix(l, 10).unwrap(),
-            usize::from_str_radix(r, 10).unwrap(),
-        )
+        Self(l.parse::<usize>().unwrap(), r.parse::<usize>().unwrap())
     }
 }
 
@@ -43,7 +40,7 @@ impl IdRange {
     }
 }
 
-struct Kitchen {
+pub struct Kitchen {
     ranges: Vec<IdRange>,
     ingredients: Vec<usize>,
 }
@@ -55,7 +52,7 @@ impl From<&str> for Kitchen {
             ranges: ranges.lines().map(IdRange::from).collect(),
             ingredients: ingredients
                 .lines()
-                .map(|l| usize::from_str_radix(l, 10).unwrap())
+                .map(|l| l.parse::<usize>().unwrap())
                 .collect(),
         }
     }
@@ -125,29 +122,10 @@ impl Kitchen {
     }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let kitchen = Kitchen::from(input);
+pub fn part_one(kitchen: &Kitchen) -> Option<usize> {
     Some(kitchen.count_fresh())
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let kitchen = Kitchen::from(input);
+pub fn part_two(kitchen: &Kitchen) -> Option<usize> {
     Some(kitchen.total_fresh())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(14));
-    }
-}
@@ -0,0 +1,133 @@
+advent_of_code::solution!(2025, 6);
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Sum,
+    Multiply,
+}
+
+impl TryFrom<&str> for Operation {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "+" => Ok(Self::Sum),
+            "*" => Ok(Self::Multiply),
+            _ => Err(format!("unexpected operation {value:?}")),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Calculus {
+    nums: Vec<usize>,
+    op: Operation,
+}
+
+impl Calculus {
+    fn new(nums: Vec<usize>, op: Operation) -> Self {
+        Self { op, nums }
+    }
+
+    fn solve(&self) -> usize {
+        match self.op {
+            Operation::Multiply => self.nums.iter().product(),
+            Operation::Sum => self.nums.iter().sum(),
+        }
+    }
+}
+
+fn parse_p1(input: &str) -> Result<Vec<Calculus>, String> {
+    let lines = input.lines();
+    let mut num_lines = Vec::new();
+    let mut ops = Vec::new();
+    for l in lines {
+        if l.contains("+") {
+            ops = l
+                .split(" ")
+                .filter(|&n| !n.is_empty())
+                .map(Operation::try_from)
+                .collect::<Result<_, _>>()?;
+            continue;
+        }
+        num_lines.push(
+            l.split(" ")
+                .filter(|&n| !n.is_empty())
+                .map(|n| {
+                    n.parse::<usize>()
+                        .map_err(|e| format!("invalid number {n:?}: {e}"))
+                })
+                .collect::<Result<Vec<usize>, _>>()?,
+        );
+    }
+
+    for nums in &num_lines {
+        if ops.len() != nums.len() {
+            return Err(format!(
+                "expected {} numbers per line, found {}",
+                ops.len(),
+                nums.len()
+            ));
+        }
+    }
+
+    let mut calculus_vec = Vec::new();
+
+    for i in 0..ops.len() {
+        calculus_vec.push(Calculus::new(
+            num_lines.iter().map(|nums| nums[i]).collect(),
+            ops[i],
+        ));
+    }
+
+    Ok(calculus_vec)
+}
+
+fn parse_p2(input: &str) -> Result<Vec<Calculus>, String> {
+    let lines = input.lines().collect::<Vec<&str>>();
+    let len = lines
+        .iter()
+        .map(|l| l.len())
+        .max()
+        .ok_or("expected at least one line")?
+        + 1;
+    let mut op = None;
+    let mut nums = Vec::new();
+    let mut calculus_vec = Vec::new();
+    for i in 0..len {
+        let mut l = lines
+            .iter()
+            .map(|&l| l.chars().nth(i).unwrap_or(' '))
+            .collect::<String>();
+
+        if l.trim().is_empty() {
+            let op = op
+                .take()
+                .ok_or_else(|| format!("missing operation before column {i}"))?;
+            calculus_vec.push(Calculus::new(nums.clone(), op));
+            nums.clear();
+            continue;
+        }
+        if l.ends_with("+") {
+            op = Some(Operation::Sum);
+            l.pop();
+        } else if l.ends_with("*") {
+            op = Some(Operation::Multiply);
+            l.pop();
+        }
+        let n = l.trim();
+        nums.push(
+            n.parse::<usize>()
+                .map_err(|e| format!("invalid number {n:?}: {e}"))?,
+        );
+    }
+    Ok(calculus_vec)
+}
+
+pub fn part_one(input: &str) -> Result<usize, String> {
+    Ok(parse_p1(input)?.into_iter().map(|c| c.solve()).sum())
+}
+
+pub fn part_two(input: &str) -> Result<usize, String> {
+    Ok(parse_p2(input)?.into_iter().map(|c| c.solve()).sum())
+}
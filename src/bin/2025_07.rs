@@ -1,9 +1,14 @@
-use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet};
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(
+    2025,
+    7,
+    parse: TachyonManyfold::from => TachyonManyfold,
+    answers: (Option<usize>, Option<usize>)
+);
 
 #[derive(Debug)]
-struct TachyonManyfold {
+pub struct TachyonManyfold {
     start: usize,
     splitters: Vec<Vec<usize>>,
 }
@@ -33,7 +38,7 @@ impl TachyonManyfold {
         for splitter_line in &self.splitters {
             let mut new_current_tachyons = BTreeSet::new();
             for tachyon in &current_tachyons {
-                if splitter_line.contains(&tachyon) {
+                if splitter_line.contains(tachyon) {
                     new_current_tachyons.insert(tachyon - 1);
                     new_current_tachyons.insert(tachyon + 1);
                     split_count += 1;
@@ -73,32 +78,13 @@ impl TachyonManyfold {
     }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let tachyon_manyfold = TachyonManyfold::from(input);
+pub fn part_one(tachyon_manyfold: &TachyonManyfold) -> Option<usize> {
     Some(tachyon_manyfold.count_tachyon_split())
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let tachyon_manyfold = TachyonManyfold::from(input);
+pub fn part_two(tachyon_manyfold: &TachyonManyfold) -> Option<usize> {
     let mut cache = BTreeMap::new();
     let tachyon_x = tachyon_manyfold.start;
     let tachyon_y = 0;
     Some(tachyon_manyfold.count_tachyon_path(tachyon_x, tachyon_y, &mut cache))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(21));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(40));
-    }
-}
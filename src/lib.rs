@@ -1,3 +1,16 @@
 pub mod template;
 
+/// Attribute macros for declaring the parts of a solution, see [`aoc::solution`].
+pub use advent_of_code_macros as aoc;
+
+// Allows solutions that reference `advent_of_code::` to be compiled as modules of this crate.
+#[cfg(feature = "registry")]
+extern crate self as advent_of_code;
+
+/// Every solution in `src/bin`, compiled into the library.
+#[cfg(feature = "registry")]
+pub mod days {
+    include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+}
+
 // Use this file to add helper functions and additional modules.
@@ -1,78 +1,156 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, history, inputs, read, scaffold, solve, time,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::PuzzleId;
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{Day, DaySelection, PuzzleId, default_year};
     use std::process;
+    use std::time::Duration;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
+        },
+        History {
+            puzzle: PuzzleId,
+        },
+        Inputs {
+            puzzle: PuzzleId,
+            release: bool,
+            time: bool,
+            timeout: Option<Duration>,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
             download: bool,
             overwrite: bool,
         },
         Solve {
-            day: Day,
+            year: u16,
+            selection: DaySelection,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            input: Option<String>,
+            example: bool,
         },
         All {
+            year: u16,
+            selection: Option<DaySelection>,
             release: bool,
+            parallel: bool,
+            timeout: Option<Duration>,
         },
         Time {
             all: bool,
-            day: Option<Day>,
+            year: u16,
+            selection: Option<DaySelection>,
             store: bool,
+            regression_threshold: Option<f64>,
+            timeout: Option<Duration>,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
+    /// Reads the `--year` option, falling back to `AOC_YEAR`.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<u16, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => default_year()
+                .ok_or_else(|| "no year given. Pass `--year <yyyy>` or set \"AOC_YEAR\".".into()),
+        }
+    }
+
+    /// Reads the `--timeout <seconds>` option.
+    fn parse_timeout(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Duration>, Box<dyn std::error::Error>> {
+        Ok(args
+            .opt_value_from_str::<_, u64>("--timeout")?
+            .map(Duration::from_secs))
+    }
+
+    /// Reads a day and its year, e.g. `8 --year 2024`.
+    fn parse_puzzle(
+        args: &mut pico_args::Arguments,
+    ) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        // NOTE: options are parsed first so that they can precede the day.
+        let year = parse_year(args)?;
+        let day = Day::from_str_in(year, &args.free_from_str::<String>()?)?;
+        Ok(PuzzleId::new(year, day))
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                year: parse_year(&mut args)?,
                 release: args.contains("--release"),
+                parallel: args.contains("--parallel"),
+                timeout: parse_timeout(&mut args)?,
+                selection: args.opt_free_from_str()?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let regression_threshold = args.opt_value_from_str("--regression-threshold")?;
+                let year = parse_year(&mut args)?;
+                let timeout = parse_timeout(&mut args)?;
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    year,
+                    selection: args.opt_free_from_str()?,
                     store,
+                    regression_threshold,
+                    timeout,
                 }
             }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
+            Some("history") => AppArguments::History {
+                puzzle: parse_puzzle(&mut args)?,
+            },
+            Some("inputs") => {
+                let release = args.contains("--release");
+                let time = args.contains("--time");
+                let timeout = parse_timeout(&mut args)?;
+
+                AppArguments::Inputs {
+                    puzzle: parse_puzzle(&mut args)?,
+                    release,
+                    time,
+                    timeout,
+                }
+            }
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                year: parse_year(&mut args)?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                input: args.opt_value_from_str("--input")?,
+                example: args.contains("--example"),
+                selection: args.free_from_str()?,
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -102,38 +180,76 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                parallel,
+                selection,
+                timeout,
+            } => all::handle(year, selection.as_ref(), release, parallel, timeout),
+            AppArguments::Time {
+                year,
+                selection,
+                all,
+                store,
+                regression_threshold,
+                timeout,
+            } => time::handle(
+                year,
+                selection.as_ref(),
+                all,
+                store,
+                regression_threshold,
+                timeout,
+            ),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::History { puzzle } => history::handle(puzzle),
+            AppArguments::Inputs {
+                puzzle,
+                release,
+                time,
+                timeout,
+            } => inputs::handle(puzzle, release, time, timeout),
             AppArguments::Scaffold {
-                day,
+                puzzle,
                 download,
                 overwrite,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(puzzle, overwrite);
                 if download {
-                    download::handle(day);
+                    download::handle(puzzle);
                 }
             }
             AppArguments::Solve {
-                day,
+                year,
+                selection,
+                release,
+                dhat,
+                submit,
+                input,
+                example,
+            } => solve::handle(
+                year,
+                &selection,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                input.as_deref(),
+                example,
+            ),
             #[cfg(feature = "today")]
             AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                match PuzzleId::today() {
+                    Some(puzzle) => {
+                        scaffold::handle(puzzle, false);
+                        download::handle(puzzle);
+                        read::handle(puzzle)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run on the days of this year's \
+                            advent calendar. Please use `scaffold` with a specific day."
                         );
                         process::exit(1)
                     }
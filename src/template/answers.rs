@@ -0,0 +1,179 @@
+/// Known-correct answers for the real puzzle inputs, stored in `data/answers.json`.
+/// Solutions are checked against these to catch refactors that change an answer.
+use std::{collections::BTreeMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    PuzzleId,
+    ocr::answer_text,
+    puzzle::{from_nested_json, to_nested_json},
+};
+
+static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// The outcome of checking an answer against the known-correct one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// No correct answer is known for this part yet.
+    Unknown,
+    Correct,
+    Incorrect {
+        expected: String,
+    },
+}
+
+impl Verdict {
+    /// Checks `answer` against the `expected` answer, if one is known.
+    /// A missing answer is incorrect when a correct one is known.
+    /// Answers drawn as letters are compared by their recognised text, see [`answer_text`].
+    pub fn of(expected: Option<&str>, answer: Option<&str>) -> Self {
+        match expected {
+            None => Verdict::Unknown,
+            Some(expected) if answer.map(answer_text).as_deref() == Some(expected) => {
+                Verdict::Correct
+            }
+            Some(expected) => Verdict::Incorrect {
+                expected: expected.into(),
+            },
+        }
+    }
+
+    /// Suffix printed after an answer, e.g. ` ✔`.
+    pub fn marker(&self) -> String {
+        match self {
+            Verdict::Unknown => String::new(),
+            Verdict::Correct => " ✔".into(),
+            Verdict::Incorrect { expected } => format!(" ✘ (expected {expected})"),
+        }
+    }
+
+    pub fn is_incorrect(&self) -> bool {
+        matches!(self, Verdict::Incorrect { .. })
+    }
+}
+
+/// Known-correct answers per puzzle and part.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    data: BTreeMap<(PuzzleId, u8), String>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns no answers.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(ANSWERS_FILE_PATH)
+            .map_err(|x| x.to_string())
+            .and_then(|s| Answers::from_str(&s))
+            .unwrap_or_default()
+    }
+
+    /// Returns the known-correct answer of a part, if present.
+    pub fn get(&self, puzzle: PuzzleId, part: u8) -> Option<&str> {
+        self.data.get(&(puzzle, part)).map(String::as_str)
+    }
+
+    /// Records the correct answer of a part, replacing any previous one.
+    pub fn insert(&mut self, puzzle: PuzzleId, part: u8, answer: String) {
+        self.data.insert((puzzle, part), answer);
+    }
+
+    /// Checks `answer` against the known-correct answer of a part.
+    pub fn check(&self, puzzle: PuzzleId, part: u8, answer: Option<&str>) -> Verdict {
+        Verdict::of(self.get(puzzle, part), answer)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        to_nested_json(
+            value.data.iter().map(|((puzzle, part), answer)| {
+                (*puzzle, *part, JsonValue::String(answer.clone()))
+            }),
+        )
+    }
+}
+
+impl TryFrom<&JsonValue> for Answers {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let mut answers = Answers::default();
+
+        for (puzzle, part, value) in from_nested_json(value, "answers")? {
+            match value {
+                JsonValue::Null => {}
+                JsonValue::String(answer) => answers.insert(puzzle, part, answer.clone()),
+                _ => return Err("Expected answers.year.day.part to be null or string.".into()),
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON file."))?;
+        Answers::try_from(&json)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Verdict};
+    use crate::{day, template::PuzzleId};
+
+    const DAY_1: PuzzleId = PuzzleId::new(2025, day!(1));
+
+    #[test]
+    fn checks_known_answers() {
+        let mut answers = Answers::default();
+        answers.insert(DAY_1, 1, "42".into());
+
+        assert_eq!(answers.check(DAY_1, 1, Some("42")), Verdict::Correct);
+        assert_eq!(
+            answers.check(DAY_1, 1, Some("41")),
+            Verdict::Incorrect {
+                expected: "42".into()
+            }
+        );
+        assert!(answers.check(DAY_1, 1, None).is_incorrect());
+        assert_eq!(answers.check(DAY_1, 2, Some("42")), Verdict::Unknown);
+    }
+
+    #[test]
+    fn roundtrips_through_json() {
+        let mut answers = Answers::default();
+        answers.insert(DAY_1, 1, "42".into());
+        answers.insert(DAY_1, 2, "1337".into());
+        answers.insert(PuzzleId::new(2024, day!(12)), 2, "ABCDEFGH".into());
+
+        let json = tinyjson::JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(json.parse::<Answers>().unwrap(), answers);
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        assert!(r#"{ "2025": { "01": { "part_1": 42 } } }"#.parse::<Answers>().is_err());
+        assert!(r#"{ "01": { "part_1": "42" } }"#.parse::<Answers>().is_err());
+        assert!(r#"{ "2025": { "day": {} } }"#.parse::<Answers>().is_err());
+        assert_eq!(
+            r#"{ "2025": { "03": { "part_1": null } } }"#.parse::<Answers>(),
+            Ok(Answers::default())
+        );
+    }
+}
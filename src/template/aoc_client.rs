@@ -0,0 +1,360 @@
+/// Built-in client for the Advent of Code website, used instead of "aoc-cli" when the `client` feature is enabled.
+use std::{env, fmt::Display, fs, io, path::PathBuf};
+
+use crate::template::PuzzleId;
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    UserAgentNotFound,
+    Request(Box<ureq::Error>),
+    BadStatus(u16),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set \"AOC_SESSION\" or paste it into \"~/.adventofcode.session\"."
+            ),
+            AocClientError::UserAgentNotFound => write!(
+                f,
+                "no user agent found. Set \"AOC_USER_AGENT\" to a way of contacting you, e.g. \"github.com/<user>/<repo> by <email>\"."
+            ),
+            AocClientError::Request(e) => write!(f, "request failed: {e}"),
+            AocClientError::BadStatus(status) => {
+                write!(f, "server responded with status {status}.")
+            }
+            AocClientError::IO(e) => write!(f, "could not write file: {e}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        AocClientError::Request(Box::new(e))
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+/// An authenticated client for the Advent of Code website.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    /// Creates a client that identifies itself with `contact`, see [`user_agent`].
+    pub fn new(base_url: &str, session: &str, contact: &str) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(user_agent(contact))
+            .build()
+            .into();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').into(),
+            session: session.trim().into(),
+            agent,
+        }
+    }
+
+    /// Creates a client from the environment:
+    /// - the session cookie is read from `AOC_SESSION` or `~/.adventofcode.session`.
+    /// - the contact of the user agent is read from `AOC_USER_AGENT`.
+    /// - the base URL can be overridden with `AOC_BASE_URL`, e.g. to test against a local server.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let session = get_session().ok_or(AocClientError::SessionNotFound)?;
+        let contact = env::var("AOC_USER_AGENT")
+            .ok()
+            .filter(|contact| !contact.trim().is_empty())
+            .ok_or(AocClientError::UserAgentNotFound)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &session, &contact))
+    }
+
+    /// Fetches the input of a puzzle.
+    pub fn input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let url = format!("{}/input", self.puzzle_url(puzzle));
+        read_response(
+            self.agent
+                .get(&url)
+                .header("Cookie", self.cookie())
+                .call()?,
+        )
+    }
+
+    /// Fetches the description of a puzzle as markdown.
+    pub fn puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let url = self.puzzle_url(puzzle);
+        let html = read_response(
+            self.agent
+                .get(&url)
+                .header("Cookie", self.cookie())
+                .call()?,
+        )?;
+        Ok(extract_articles(&html))
+    }
+
+    /// Posts an answer and returns the text of the server's response.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let url = format!("{}/answer", self.puzzle_url(puzzle));
+        let level = part.to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", self.cookie())
+            .send_form([("level", level.as_str()), ("answer", answer)])?;
+        Ok(extract_articles(&read_response(response)?))
+    }
+
+    fn puzzle_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn read_response(mut response: ureq::http::Response<ureq::Body>) -> Result<String, AocClientError> {
+    let status = response.status().as_u16();
+    if status != 200 {
+        return Err(AocClientError::BadStatus(status));
+    }
+    Ok(response.body_mut().read_to_string()?)
+}
+
+// NOTE: requests are identified so that the maintainers of Advent of Code can reach out about misbehaving tools.
+fn user_agent(contact: &str) -> String {
+    format!(
+        "{} (advent_of_code {})",
+        contact.trim(),
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+pub fn check() -> Result<(), AocClientError> {
+    Client::from_env().map(|_| ())
+}
+
+pub fn read(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let description = Client::from_env()?.puzzle(puzzle)?;
+    fs::write(puzzle.puzzle_path(), &description)?;
+    println!("{description}");
+    Ok(())
+}
+
+pub fn download(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = Client::from_env()?;
+    let input_path = puzzle.input_path();
+    let puzzle_path = puzzle.puzzle_path();
+
+    fs::write(&input_path, client.input(puzzle)?)?;
+    fs::write(&puzzle_path, client.puzzle(puzzle)?)?;
+
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+pub fn submit(puzzle: PuzzleId, part: u8, result: &str) -> Result<String, AocClientError> {
+    let response = Client::from_env()?.submit(puzzle, part, result)?;
+    println!("{response}");
+    Ok(response)
+}
+
+fn get_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session);
+    }
+
+    let path = PathBuf::from(env::var("HOME").ok()?).join(".adventofcode.session");
+    fs::read_to_string(path).ok()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Extracts the contents of all `<article>` elements of a page as markdown.
+fn extract_articles(html: &str) -> String {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+        articles.push(html_to_markdown(&rest[start..start + end]));
+        rest = &rest[start + end..];
+    }
+
+    articles.join("\n\n")
+}
+
+/// Converts the subset of HTML used in puzzle descriptions to markdown.
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut is_pre = false;
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        out.push_str(&decode_entities(&rest[..start]));
+
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+
+        let tag = &rest[start + 1..start + end];
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .find(|x| !x.is_empty())
+            .unwrap_or_default();
+        let is_closing = tag.starts_with('/');
+
+        match (name, is_closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p" | "ul", true) => out.push_str("\n\n"),
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("pre", false) => {
+                is_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                is_pre = false;
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !is_pre => out.push('`'),
+            ("em", _) => out.push('*'),
+            _ => {}
+        }
+
+        rest = &rest[start + end + 1..];
+    }
+
+    out.push_str(&decode_entities(rest));
+    out.trim().into()
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::{Client, extract_articles, html_to_markdown};
+    use crate::{day, template::PuzzleId};
+
+    #[test]
+    fn converts_puzzle_html() {
+        let html = "<h2>--- Day 1: Test ---</h2><p>Find <em>the</em> sum of <code>a &lt; b</code>:</p>\
+            <pre><code>1\n2\n</code></pre><ul><li>one</li><li>two</li></ul>";
+        assert_eq!(
+            html_to_markdown(html),
+            "## --- Day 1: Test ---\n\nFind *the* sum of `a < b`:\n\n```\n1\n2\n```\n\n- one\n- two"
+        );
+    }
+
+    #[test]
+    fn extracts_articles() {
+        let html = r#"<main><article class="day-desc"><p>one</p></article><p>skipped</p><article><p>two</p></article></main>"#;
+        assert_eq!(extract_articles(html), "one\n\ntwo");
+    }
+
+    #[test]
+    fn submits_answers_to_base_url() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut head = String::new();
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                head.push_str(&line);
+            }
+
+            let length: usize = head
+                .lines()
+                .find_map(|l| {
+                    l.to_lowercase()
+                        .strip_prefix("content-length: ")
+                        .map(str::to_owned)
+                })
+                .unwrap()
+                .parse()
+                .unwrap();
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+
+            let page = "<article><p>That's the right answer!</p></article>";
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{page}",
+                page.len()
+            )
+            .unwrap();
+
+            (head, String::from_utf8(body).unwrap())
+        });
+
+        let client = Client::new(
+            &base_url,
+            "secret\n",
+            "github.com/user/repo by user@example.com",
+        );
+        let response = client
+            .submit(PuzzleId::new(2025, day!(3)), 2, "42")
+            .unwrap();
+        assert_eq!(response, "That's the right answer!");
+
+        let (head, body) = server.join().unwrap();
+        assert!(head.starts_with("POST /2025/day/3/answer "));
+        assert!(head.contains("session=secret"));
+        assert!(
+            head.to_lowercase()
+                .contains("user-agent: github.com/user/repo by user@example.com (advent_of_code")
+        );
+        assert_eq!(body, "level=2&answer=42");
+    }
+}
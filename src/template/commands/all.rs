@@ -1,5 +1,19 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::time::Duration;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{DaySelection, PuzzleId, all_days, run_multi::run_multi};
+
+use super::select_puzzles;
+
+pub fn handle(
+    year: u16,
+    selection: Option<&DaySelection>,
+    is_release: bool,
+    is_parallel: bool,
+    timeout: Option<Duration>,
+) {
+    let puzzles = selection.map_or_else(
+        || all_days(year).map(|day| PuzzleId::new(year, day)).collect(),
+        |selection| select_puzzles(year, selection),
+    );
+    run_multi(&puzzles, is_release, false, is_parallel, timeout).exit_on_failure();
 }
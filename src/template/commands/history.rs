@@ -0,0 +1,59 @@
+use crate::template::history::{self, format_timestamp};
+use crate::template::record::{COMBINED, PARSE, part_label};
+use crate::template::timings;
+use crate::template::{ANSI_BOLD, ANSI_RESET, PuzzleId};
+
+pub fn handle(puzzle: PuzzleId) {
+    let runs = history::read_from_file();
+
+    let day_runs: Vec<_> = runs
+        .iter()
+        .filter(|run| run.timings.get(puzzle).is_some())
+        .collect();
+
+    println!("{ANSI_BOLD}{} Day {}{ANSI_RESET}", puzzle.year, puzzle.day);
+    println!("-----------");
+
+    if day_runs.is_empty() {
+        println!(
+            "No benchmark history. Run `cargo time {} --year {} --store` to record one.",
+            puzzle.day, puzzle.year
+        );
+        return;
+    }
+
+    println!(
+        "{:<18}{:<10}{:<12}{:<12}{:<12}Combined",
+        "Date (UTC)", "Commit", "Parse", "Part 1", "Part 2"
+    );
+
+    for run in day_runs {
+        println!(
+            "{:<18}{:<10}{:<12}{:<12}{:<12}{}",
+            format_timestamp(run.timestamp),
+            run.commit.as_deref().unwrap_or("-"),
+            format_nanos(run.part_nanos(puzzle, PARSE)),
+            format_nanos(run.part_nanos(puzzle, 1)),
+            format_nanos(run.part_nanos(puzzle, 2)),
+            format_nanos(run.part_nanos(puzzle, COMBINED)),
+        );
+    }
+
+    println!();
+
+    for part in [PARSE, 1, 2, COMBINED] {
+        if let Some((run, nanos)) = history::best(&runs, puzzle, part) {
+            println!(
+                "{ANSI_BOLD}Best {}:{ANSI_RESET} {} ({}, {})",
+                part_label(part),
+                format_nanos(Some(nanos)),
+                format_timestamp(run.timestamp),
+                run.commit.as_deref().unwrap_or("-"),
+            );
+        }
+    }
+}
+
+fn format_nanos(nanos: Option<f64>) -> String {
+    nanos.map_or_else(|| "-".into(), timings::format_nanos)
+}
@@ -0,0 +1,89 @@
+use std::process;
+use std::time::Duration;
+
+use crate::template::answers::Verdict;
+use crate::template::input::extra_inputs;
+use crate::template::run_multi::{Exit, Output, Status, print_record, run_input};
+use crate::template::{ANSI_BOLD, ANSI_RESET, PuzzleId};
+
+pub fn handle(puzzle: PuzzleId, is_release: bool, is_timed: bool, timeout: Option<Duration>) {
+    let inputs = match extra_inputs(puzzle) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    if inputs.is_empty() {
+        println!(
+            "No additional inputs. Add them to \"{}/<name>.txt\".",
+            puzzle.extra_inputs_dir()
+        );
+        return;
+    }
+
+    let (mut passed, mut unchecked, mut failed) = (0, 0, vec![]);
+
+    for (i, input) in inputs.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!(
+            "{ANSI_BOLD}{} Day {} · {}{ANSI_RESET}",
+            puzzle.year, puzzle.day, input.name
+        );
+        println!("-----------");
+
+        let (records, exit) = run_input(
+            puzzle,
+            Some(&input.path),
+            None,
+            is_release,
+            is_timed,
+            timeout,
+            &Output::forwarded(),
+        );
+
+        let verdicts: Vec<_> = records
+            .iter()
+            .map(|record| Verdict::of(input.answer(record.part), record.answer.as_deref()))
+            .collect();
+
+        if records.is_empty() && exit == Exit::Success {
+            println!("Not solved.");
+        }
+
+        for (record, verdict) in records.iter().zip(&verdicts) {
+            print_record(record, verdict);
+        }
+
+        let status = Status::of(&records, exit);
+        if matches!(status, Status::Crashed(_) | Status::TimedOut(_)) {
+            eprintln!("{} {status}.", input.name);
+        }
+
+        if status.is_failure() || verdicts.iter().any(Verdict::is_incorrect) {
+            failed.push(input.name.as_str());
+        } else if verdicts.contains(&Verdict::Correct) {
+            passed += 1;
+        } else {
+            unchecked += 1;
+        }
+    }
+
+    println!(
+        "\n{ANSI_BOLD}Passed:{ANSI_RESET} {passed}, {ANSI_BOLD}failed:{ANSI_RESET} {}, \
+        {ANSI_BOLD}unchecked:{ANSI_RESET} {unchecked}",
+        failed.len()
+    );
+
+    if !failed.is_empty() {
+        eprintln!(
+            "\n{} input(s) did not pass: {}",
+            failed.len(),
+            failed.join(", ")
+        );
+        process::exit(1);
+    }
+}
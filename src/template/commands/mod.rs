@@ -1,6 +1,23 @@
 pub mod all;
 pub mod download;
+pub mod history;
+pub mod inputs;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
 pub mod time;
+
+use std::{collections::HashSet, process};
+
+use crate::template::{DaySelection, PuzzleId, answers::Answers, timings::Timings};
+
+/// Resolves a day selection against the stored answers and timings, exiting on invalid days.
+fn select_puzzles(year: u16, selection: &DaySelection) -> HashSet<PuzzleId> {
+    match selection.resolve(year, &Answers::read_from_file(), &Timings::read_from_file()) {
+        Ok(puzzles) => puzzles,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
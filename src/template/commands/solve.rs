@@ -1,9 +1,77 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{ANSI_BOLD, ANSI_RESET, DaySelection, PuzzleId};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+use super::select_puzzles;
+
+pub fn handle(
+    year: u16,
+    selection: &DaySelection,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    input: Option<&str>,
+    example: bool,
+) {
+    let mut puzzles: Vec<_> = select_puzzles(year, selection).into_iter().collect();
+    puzzles.sort_unstable();
+
+    if submit_part.is_some() && puzzles.len() != 1 {
+        eprintln!(
+            "`--submit` needs exactly one day, but {} were selected.",
+            puzzles.len()
+        );
+        process::exit(1);
+    }
+
+    if input.is_some() && puzzles.len() != 1 {
+        eprintln!(
+            "`--input` needs exactly one day, but {} were selected.",
+            puzzles.len()
+        );
+        process::exit(1);
+    }
+
+    // NOTE: the remaining days still run if one of them fails, the command fails once all of them ran.
+    let mut failures = vec![];
+
+    let print_headings = puzzles.len() > 1;
+    for (i, puzzle) in puzzles.into_iter().enumerate() {
+        if print_headings {
+            if i > 0 {
+                println!();
+            }
+            println!("{ANSI_BOLD}{} Day {}{ANSI_RESET}", puzzle.year, puzzle.day);
+            println!("------");
+        }
+        if !solve(puzzle, release, dhat, submit_part, input, example) {
+            failures.push(puzzle.to_string());
+        }
+    }
+
+    if !failures.is_empty() {
+        if print_headings {
+            eprintln!(
+                "\n{} day(s) did not succeed: {}",
+                failures.len(),
+                failures.join(", ")
+            );
+        }
+        process::exit(1);
+    }
+}
+
+/// Runs the solution of a puzzle, returns whether it succeeded.
+/// Solutions exit with an error if a part failed or an answer does not match the known-correct answer.
+fn solve(
+    puzzle: PuzzleId,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    input: Option<&str>,
+    example: bool,
+) -> bool {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.bin_name()];
 
     if dhat {
         cmd_args.extend([
@@ -23,12 +91,26 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
-    let mut cmd = Command::new("cargo")
+    if let Some(input) = input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input.to_string());
+    }
+
+    if example {
+        cmd_args.push("--example".to_string());
+    }
+
+    let status = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+        .status();
 
-    cmd.wait().unwrap();
+    match status {
+        Ok(status) => status.success(),
+        Err(e) => {
+            eprintln!("could not run {puzzle}: {e}");
+            false
+        }
+    }
 }
@@ -1,32 +1,59 @@
 use std::collections::HashSet;
+use std::process;
+use std::time::Duration;
 
+use crate::template::history::{self, Run};
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{DaySelection, PuzzleId, all_days, comparison, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+use super::select_puzzles;
+
+pub fn handle(
+    year: u16,
+    selection: Option<&DaySelection>,
+    run_all: bool,
+    store: bool,
+    regression_threshold: Option<f64>,
+    timeout: Option<Duration>,
+) {
     let stored_timings = Timings::read_from_file();
 
-    let days_to_run = day.map_or_else(
+    let puzzles_to_run = selection.map_or_else(
         || {
+            let puzzles = all_days(year).map(|day| PuzzleId::new(year, day));
             if run_all {
-                all_days().collect()
+                puzzles.collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                puzzles
+                    .filter(|puzzle| !stored_timings.is_day_complete(*puzzle))
                     .collect()
             }
         },
-        |day| HashSet::from([day]),
+        |selection| select_puzzles(year, selection),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let run = run_multi(&puzzles_to_run, true, true, false, timeout);
+
+    // NOTE: days that failed, crashed or timed out are left out, the timings of the other days are still stored.
+    let failed: HashSet<PuzzleId> = run.failures().map(|(puzzle, _)| *puzzle).collect();
+    let mut timings = run.timings.clone().unwrap();
+    timings
+        .data
+        .retain(|timing| !failed.contains(&timing.puzzle));
+
+    let changes = comparison::compare(&stored_timings, &timings);
+    let regressions = comparison::print_changes(&changes, regression_threshold);
 
-    if store {
+    if store && regressions.is_empty() && !timings.data.is_empty() {
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
+        if let Err(e) = history::append(&Run::new(timings)) {
+            eprintln!("Failed to append benchmarks to history: {e}");
+        }
+
         println!();
         match readme_benchmarks::update(merged_timings) {
             Ok(()) => {
@@ -37,4 +64,18 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if !regressions.is_empty() {
+        eprintln!(
+            "\n{} part(s) regressed by more than {}%.",
+            regressions.len(),
+            regression_threshold.unwrap_or_default()
+        );
+    }
+
+    run.exit_on_failure();
+
+    if !regressions.is_empty() {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,157 @@
+/// Compares fresh benchmark timings against the baseline stored in `data/timings.json`.
+use crate::template::record::{COMBINED, PARSE, part_label};
+use crate::template::timings::{Timings, format_nanos};
+use crate::template::{ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET, PuzzleId};
+
+/// Changes within this percentage are considered noise and not highlighted.
+const NOISE_PERCENT: f64 = 1.0;
+
+/// The change of a part's mean execution time relative to the baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub baseline_nanos: f64,
+    pub current_nanos: f64,
+}
+
+impl Change {
+    /// Relative change in percent, positive values are slowdowns.
+    pub fn percent(&self) -> f64 {
+        (self.current_nanos - self.baseline_nanos) / self.baseline_nanos * 100_f64
+    }
+
+    /// Whether the part got slower by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
+/// Collects changes for every part that has a timing in both `baseline` and `current`.
+pub fn compare(baseline: &Timings, current: &Timings) -> Vec<Change> {
+    current
+        .data
+        .iter()
+        .filter_map(|timing| Some((timing, baseline.get(timing.puzzle)?)))
+        .flat_map(|(timing, baseline)| {
+            [PARSE, 1, 2, COMBINED].into_iter().filter_map(|part| {
+                Some(Change {
+                    puzzle: timing.puzzle,
+                    part,
+                    baseline_nanos: baseline.part_nanos(part).filter(|x| *x > 0_f64)?,
+                    current_nanos: timing.part_nanos(part)?,
+                })
+            })
+        })
+        .collect()
+}
+
+/// Prints the changes, highlighting slowdowns in red and speedups in green.
+/// Returns the changes that regressed beyond `threshold`, if one is set.
+pub fn print_changes(changes: &[Change], threshold: Option<f64>) -> Vec<&Change> {
+    if changes.is_empty() {
+        return vec![];
+    }
+
+    println!("\n{ANSI_BOLD}Compared to stored timings:{ANSI_RESET}");
+
+    for change in changes {
+        let percent = change.percent();
+
+        let color = if percent > NOISE_PERCENT {
+            ANSI_RED
+        } else if percent < -NOISE_PERCENT {
+            ANSI_GREEN
+        } else {
+            ANSI_RESET
+        };
+
+        let marker = match threshold {
+            Some(threshold) if change.is_regression(threshold) => " ✖",
+            _ => "",
+        };
+
+        println!(
+            "{} {}: {} → {} ({color}{percent:+.1}%{ANSI_RESET}){marker}",
+            change.puzzle,
+            part_label(change.part),
+            format_nanos(change.baseline_nanos),
+            format_nanos(change.current_nanos),
+        );
+    }
+
+    threshold
+        .map(|threshold| {
+            changes
+                .iter()
+                .filter(|change| change.is_regression(threshold))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Change, compare};
+    use crate::{
+        day,
+        template::{
+            Day, PuzzleId,
+            stats::Stats,
+            timings::{Timing, Timings},
+        },
+    };
+
+    fn timing(day: u8, part_1: Option<u64>, part_2: Option<u64>) -> Timing {
+        let stats = |nanos: u64| Stats::from_mean(Duration::from_nanos(nanos));
+        Timing {
+            puzzle: PuzzleId::new(2025, Day::new(day).unwrap()),
+            parse: None,
+            part_1: part_1.map(stats),
+            part_2: part_2.map(stats),
+            combined: None,
+            total_nanos: 0_f64,
+        }
+    }
+
+    #[test]
+    fn compares_overlapping_parts() {
+        let baseline = Timings {
+            data: vec![
+                timing(1, Some(100), Some(1_000_000)),
+                timing(2, Some(1_000), None),
+            ],
+        };
+        let current = Timings {
+            data: vec![
+                timing(1, Some(150), Some(500_000)),
+                timing(2, Some(1_000), Some(1_000)),
+                timing(3, Some(1_000), Some(1_000)),
+            ],
+        };
+
+        let changes = compare(&baseline, &current);
+        assert_eq!(changes.len(), 3);
+        assert_eq!((changes[0].puzzle.day, changes[0].part), (day!(1), 1));
+        assert_eq!(changes[0].percent(), 50_f64);
+        assert_eq!(changes[1].percent(), -50_f64);
+        assert_eq!((changes[2].puzzle.day, changes[2].part), (day!(2), 1));
+        assert_eq!(changes[2].percent(), 0_f64);
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let change = Change {
+            puzzle: PuzzleId::new(2025, day!(1)),
+            part: 1,
+            baseline_nanos: 100_f64,
+            current_nanos: 111_f64,
+        };
+        assert!(change.is_regression(10_f64));
+        assert!(!change.is_regression(20_f64));
+    }
+}
@@ -3,12 +3,98 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 #[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
+use crate::template::PuzzleId;
 
-#[cfg(feature = "today")]
-const SERVER_UTC_OFFSET: i32 = -5;
+/// The highest day number of any advent calendar.
+pub const MAX_DAY: u8 = 25;
+
+/// Length of the advent calendar, starting from a given year until the next entry.
+/// Add an entry here when an event changes its number of days, or configure it with `AOC_CALENDAR_LENGTHS`.
+const CALENDAR_LENGTHS: &[(u16, u8)] = &[(2015, 25), (2025, 12)];
+
+/// Additional entries of [`CALENDAR_LENGTHS`] as `year=days` pairs, e.g. `"2030=25,2031=12"`.
+/// Read from `AOC_CALENDAR_LENGTHS` when compiling, which can be set in `.cargo/config.toml` like `AOC_YEAR`.
+const CONFIGURED_LENGTHS: &str = match option_env!("AOC_CALENDAR_LENGTHS") {
+    Some(lengths) => lengths,
+    None => "",
+};
+
+// NOTE: checks the configured lengths when compiling, so that a malformed entry fails the build.
+const _: u8 = calendar_length(0);
+
+/// Returns the number of puzzle days of the event in `year`.
+pub const fn calendar_length(year: u16) -> u8 {
+    calendar_length_with(year, CONFIGURED_LENGTHS.as_bytes())
+}
+
+/// Returns the number of puzzle days of the event in `year`, with `configured` entries taking precedence.
+const fn calendar_length_with(year: u16, mut configured: &[u8]) -> u8 {
+    let (mut since, mut length) = (0, MAX_DAY);
+
+    let mut i = 0;
+    while i < CALENDAR_LENGTHS.len() {
+        let (entry_since, days) = CALENDAR_LENGTHS[i];
+        if entry_since <= year && entry_since >= since {
+            (since, length) = (entry_since, days);
+        }
+        i += 1;
+    }
+
+    while let Some((entry_since, days, rest)) = next_calendar_length(configured) {
+        if entry_since <= year && entry_since >= since {
+            (since, length) = (entry_since, days);
+        }
+        configured = rest;
+    }
+
+    length
+}
+
+/// Parses the next `year=days` pair of a comma-separated list, returning it along with the rest of the list.
+/// Panics if the pair is malformed.
+#[allow(clippy::cast_lossless)]
+const fn next_calendar_length(mut bytes: &[u8]) -> Option<(u16, u8, &[u8])> {
+    let (mut year, mut days) = (0_u16, 0_u8);
+    let (mut is_days, mut is_empty) = (false, true);
+
+    while let [byte, rest @ ..] = bytes {
+        bytes = rest;
+        match *byte {
+            b',' => break,
+            b' ' => continue,
+            b'=' if !is_days => is_days = true,
+            b'0'..=b'9' if is_days => days = days.saturating_mul(10).saturating_add(*byte - b'0'),
+            b'0'..=b'9' => {
+                year = year
+                    .saturating_mul(10)
+                    .saturating_add((*byte - b'0') as u16)
+            }
+            _ => panic!(
+                "invalid `AOC_CALENDAR_LENGTHS`, expecting `year=days` pairs, e.g. \"2030=25,2031=12\""
+            ),
+        }
+        is_empty = false;
+    }
+
+    if is_empty {
+        return if bytes.is_empty() {
+            None
+        } else {
+            next_calendar_length(bytes)
+        };
+    }
+
+    if !is_days || days == 0 || days > MAX_DAY {
+        panic!(
+            "invalid `AOC_CALENDAR_LENGTHS`, expecting `year=days` pairs with 1 to 25 days, e.g. \"2030=25\""
+        );
+    }
+
+    Some((year, days, bytes))
+}
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// Use [`Day::new_in`] to check a day against the calendar of a given year.
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -25,12 +111,30 @@ impl Day {
     /// Creates a [`Day`] from the provided value if it's in the valid range,
     /// returns [`None`] otherwise.
     pub const fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
+        if day == 0 || day > MAX_DAY {
+            return None;
+        }
+        Some(Self(day))
+    }
+
+    /// Creates a [`Day`] from the provided value if it's part of the calendar of `year`,
+    /// returns [`None`] otherwise.
+    pub const fn new_in(year: u16, day: u8) -> Option<Self> {
+        if day == 0 || day > calendar_length(year) {
             return None;
         }
         Some(Self(day))
     }
 
+    /// Parses a [`Day`] that is part of the calendar of `year`.
+    pub fn from_str_in(year: u16, s: &str) -> Result<Self, DayFromStrError> {
+        let err = DayFromStrError {
+            max: calendar_length(year),
+        };
+        let day = s.parse().map_err(|_| err)?;
+        Self::new_in(year, day).ok_or(err)
+    }
+
     /// Converts the [`Day`] into an [`u8`].
     pub fn into_inner(self) -> u8 {
         self.0
@@ -39,15 +143,9 @@ impl Day {
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if it's part of this year's calendar, `None` otherwise.
     pub fn today() -> Option<Self> {
-        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
-        } else {
-            None
-        }
+        PuzzleId::today().map(|puzzle| puzzle.day)
     }
 }
 
@@ -75,39 +173,46 @@ impl FromStr for Day {
     type Err = DayFromStrError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        let err = DayFromStrError { max: MAX_DAY };
+        let day = s.parse().map_err(|_| err)?;
+        Self::new(day).ok_or(err)
     }
 }
 
 /// An error which can be returned when parsing a [`Day`].
-#[derive(Debug)]
-pub struct DayFromStrError;
+#[derive(Debug, Clone, Copy)]
+pub struct DayFromStrError {
+    /// The highest valid day number.
+    max: u8,
+}
 
 impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        write!(f, "expecting a day number between 1 and {}", self.max)
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// An iterator that yields every day of the calendar of `year`, starting from the 1st.
+pub fn all_days(year: u16) -> AllDays {
+    AllDays::new(year)
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of the calendar of a year, starting from the 1st.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    pub fn new(year: u16) -> Self {
+        Self {
+            current: 1,
+            last: calendar_length(year),
+        }
     }
 }
 
@@ -115,10 +220,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and calendars are never longer than `MAX_DAY`.
         let day = Day(self.current);
         self.current += 1;
 
@@ -129,6 +234,7 @@ impl Iterator for AllDays {
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
+/// If a year is passed, the day is checked against the calendar of that year, e.g. `day!(2025, 12)`.
 #[macro_export]
 macro_rules! day {
     ($day:expr) => {
@@ -137,17 +243,23 @@ macro_rules! day {
                 .expect("invalid day number, expecting a value between 1 and 25")
         }
     };
+    ($year:expr, $day:expr) => {
+        const {
+            $crate::template::Day::new_in($year, $day)
+                .expect("invalid day number, not part of this year's calendar")
+        }
+    };
 }
 
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{Day, all_days};
+    use super::{Day, all_days, calendar_length, calendar_length_with};
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(2024);
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -176,6 +288,48 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn shorter_calendars() {
+        assert_eq!(calendar_length(2015), 25);
+        assert_eq!(calendar_length(2024), 25);
+        assert_eq!(calendar_length(2025), 12);
+        assert_eq!(all_days(2025).last(), Some(Day(12)));
+
+        assert_eq!(Day::new_in(2025, 12), Some(Day(12)));
+        assert_eq!(Day::new_in(2025, 13), None);
+        assert_eq!(Day::new_in(2024, 13), Some(Day(13)));
+    }
+
+    #[test]
+    fn configured_calendar_lengths() {
+        let configured = b"2030=25, 2031=12,";
+        assert_eq!(calendar_length_with(2029, configured), 12);
+        assert_eq!(calendar_length_with(2030, configured), 25);
+        assert_eq!(calendar_length_with(2035, configured), 12);
+        assert_eq!(calendar_length_with(2024, b"2025=25"), 25);
+        assert_eq!(calendar_length_with(2025, b"2025=25"), 25);
+        assert_eq!(calendar_length_with(2025, b""), 12);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid `AOC_CALENDAR_LENGTHS`")]
+    fn rejects_malformed_calendar_lengths() {
+        calendar_length_with(2025, b"2025:12");
+    }
+
+    #[test]
+    fn parses_days_of_a_year() {
+        assert_eq!(Day::from_str_in(2025, "7").unwrap(), Day(7));
+        assert_eq!(
+            Day::from_str_in(2025, "13").unwrap_err().to_string(),
+            "expecting a day number between 1 and 12"
+        );
+        assert_eq!(
+            "26".parse::<Day>().unwrap_err().to_string(),
+            "expecting a day number between 1 and 25"
+        );
+    }
 }
 
 /* -------------------------------------------------------------------------- */
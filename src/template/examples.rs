@@ -0,0 +1,155 @@
+/// Expected answers of a day's examples, listed in the manifest `data/{year}/examples/{dd}.manifest`.
+/// The `solution!` macro generates a test that runs every part against every listed example.
+use std::collections::BTreeMap;
+use std::{fs, io};
+
+use crate::template::{PuzzleId, input::parse_answers, ocr::answer_text};
+
+/// An example file along with the expected answers of its parts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    /// Name of the file in `data/{year}/examples`, e.g. `01.txt` or `01-2.txt`.
+    pub file: String,
+    /// Expected answers by part.
+    pub answers: BTreeMap<u8, String>,
+}
+
+/// A solution part, or a named variant of it, as checked against the examples.
+pub struct CheckedPart<'a> {
+    pub part: u8,
+    pub name: Option<&'static str>,
+    pub run: &'a dyn Fn(&str) -> Result<Option<String>, String>,
+}
+
+/// Reads the example manifest of `puzzle`. Returns no examples if there is no manifest.
+pub fn read_manifest(puzzle: PuzzleId) -> Result<Vec<Example>, String> {
+    let path = puzzle.data_path("examples", "manifest");
+    match fs::read_to_string(&path) {
+        Ok(manifest) => {
+            parse_manifest(&manifest).map_err(|e| format!("invalid manifest \"{path}\": {e}"))
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(format!("could not read \"{path}\": {e}")),
+    }
+}
+
+/// Parses a manifest of `[<file>]` sections, each followed by the expected answers in the format `<part>: <answer>`.
+/// Lines starting with `#` are comments.
+fn parse_manifest(s: &str) -> Result<Vec<Example>, String> {
+    let mut sections: Vec<(String, String)> = vec![];
+
+    for line in s.lines().map(str::trim) {
+        if line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(file) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            sections.push((file.trim().to_string(), String::new()));
+            continue;
+        }
+
+        match sections.last_mut() {
+            Some((_, answers)) => {
+                answers.push_str(line);
+                answers.push('\n');
+            }
+            None if line.is_empty() => {}
+            None => return Err(format!("expected \"[<file>]\", found \"{line}\"")),
+        }
+    }
+
+    sections
+        .into_iter()
+        .map(|(file, answers)| {
+            let answers = parse_answers(&answers).map_err(|e| format!("[{file}]: {e}"))?;
+            Ok(Example { file, answers })
+        })
+        .collect()
+}
+
+/// Runs `parts` against every example in the manifest of `puzzle` and panics with a list of all mismatches.
+pub fn check_examples(puzzle: PuzzleId, parts: &[CheckedPart]) {
+    let examples = read_manifest(puzzle).unwrap_or_else(|e| panic!("{e}"));
+    let mut failures = vec![];
+
+    for example in &examples {
+        let path = format!("data/{}/examples/{}", puzzle.year, example.file);
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                failures.push(format!("{}: could not open \"{path}\": {e}", example.file));
+                continue;
+            }
+        };
+
+        for (part, expected) in &example.answers {
+            let mut checked = parts.iter().filter(|p| p.part == *part).peekable();
+
+            if checked.peek().is_none() {
+                failures.push(format!("{}: part {part} is not implemented", example.file));
+            }
+
+            for checked_part in checked {
+                let label = match checked_part.name {
+                    Some(name) => format!("part {part} [{name}]"),
+                    None => format!("part {part}"),
+                };
+
+                match (checked_part.run)(&input) {
+                    Ok(Some(answer)) if answer_text(&answer) == *expected => {}
+                    Ok(Some(answer)) => failures.push(format!(
+                        "{}: {label} returned {answer}, expected {expected}",
+                        example.file
+                    )),
+                    Ok(None) => failures.push(format!(
+                        "{}: {label} returned no answer, expected {expected}",
+                        example.file
+                    )),
+                    Err(e) => failures.push(format!("{}: {label} failed: {e}", example.file)),
+                }
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} check(s) of {puzzle} did not match the example manifest:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{Example, parse_manifest};
+
+    #[test]
+    fn parses_manifests() {
+        let manifest = "# examples of day 1\n[01.txt]\n1: 3\n2: 6\n\n[01-2.txt]\n2: 10\n";
+        assert_eq!(
+            parse_manifest(manifest),
+            Ok(vec![
+                Example {
+                    file: "01.txt".into(),
+                    answers: BTreeMap::from([(1, "3".into()), (2, "6".into())]),
+                },
+                Example {
+                    file: "01-2.txt".into(),
+                    answers: BTreeMap::from([(2, "10".into())]),
+                },
+            ])
+        );
+        assert_eq!(parse_manifest(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn rejects_invalid_manifests() {
+        assert!(parse_manifest("1: 3").is_err());
+        assert!(parse_manifest("[01.txt]\n3: 3").is_err());
+        assert!(parse_manifest("[01.txt]\n42").is_err());
+    }
+}
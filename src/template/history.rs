@@ -0,0 +1,257 @@
+/// Append-only history of benchmark runs, stored next to `data/timings.json`.
+/// Each line of the history file holds one JSON-encoded [`Run`].
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{Error, Write},
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::PuzzleId;
+use crate::template::timings::Timings;
+
+static HISTORY_FILE_PATH: &str = "./data/timings_history.jsonl";
+
+/// The timings of a single `cargo time` run.
+#[derive(Clone, Debug)]
+pub struct Run {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the checked out git commit, if available.
+    pub commit: Option<String>,
+    pub timings: Timings,
+}
+
+impl Run {
+    /// Creates a run for `timings` at the current time and git commit.
+    pub fn new(timings: Timings) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+            commit: get_git_commit(),
+            timings,
+        }
+    }
+
+    /// Mean execution time of a part of `puzzle` in nanoseconds, if it was benched in this run.
+    /// Also accepts [`PARSE`](crate::template::record::PARSE) and [`COMBINED`](crate::template::record::COMBINED).
+    pub fn part_nanos(&self, puzzle: PuzzleId, part: u8) -> Option<f64> {
+        self.timings.get(puzzle)?.part_nanos(part)
+    }
+}
+
+/// Appends a run to the history file.
+pub fn append(run: &Run) -> Result<(), Error> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY_FILE_PATH)?;
+
+    let line = JsonValue::from(run)
+        .stringify()
+        .expect("run should be serializable");
+
+    writeln!(file, "{line}")
+}
+
+/// Reads all runs from the history file, oldest first. If not present, returns an empty history.
+pub fn read_from_file() -> Vec<Run> {
+    fs::read_to_string(HISTORY_FILE_PATH)
+        .unwrap_or_default()
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match Run::from_str(line) {
+            Ok(run) => Some(run),
+            Err(e) => {
+                eprintln!("Skipping malformed history entry: {e}");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Returns the run with the fastest time for a part of `puzzle`, its parse phase or combined solution, along with that time.
+pub fn best(runs: &[Run], puzzle: PuzzleId, part: u8) -> Option<(&Run, f64)> {
+    runs.iter()
+        .filter_map(|run| Some((run, run.part_nanos(puzzle, part)?)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+fn get_git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|commit| !commit.is_empty())
+}
+
+/// Formats a unix timestamp as an UTC date time, e.g. `2025-12-01 05:00`.
+pub fn format_timestamp(timestamp: u64) -> String {
+    // see: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = timestamp / 86400;
+    let secs_of_day = timestamp % 86400;
+
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Run> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Run) -> Self {
+        let mut json = JsonValue::from(value.timings.clone());
+
+        if let JsonValue::Object(map) = &mut json {
+            map.insert(
+                "timestamp".into(),
+                JsonValue::Number(value.timestamp as f64),
+            );
+            map.insert(
+                "commit".into(),
+                match &value.commit {
+                    Some(x) => JsonValue::String(x.clone()),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        json
+    }
+}
+
+impl FromStr for Run {
+    type Err = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON."))?;
+
+        let map = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected run to be a JSON object.")?;
+
+        let timestamp = map
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected run.timestamp to be a number.")?;
+
+        let commit = map
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected run.commit to be null or string.")?;
+
+        Ok(Run {
+            timestamp,
+            commit: commit.cloned(),
+            timings: Timings::try_from(&json)?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Run, best, format_timestamp};
+    use crate::{
+        day,
+        template::{
+            PuzzleId,
+            record::{COMBINED, PARSE},
+            stats::Stats,
+            timings::{Timing, Timings},
+        },
+    };
+
+    const DAY_2: PuzzleId = PuzzleId::new(2025, day!(2));
+
+    fn run(timestamp: u64, part_1_millis: f64) -> Run {
+        Run {
+            timestamp,
+            commit: Some("abc1234".into()),
+            timings: Timings {
+                data: vec![Timing {
+                    puzzle: DAY_2,
+                    parse: None,
+                    part_1: Some(Stats::from_mean(Duration::from_secs_f64(
+                        part_1_millis / 1000_f64,
+                    ))),
+                    part_2: None,
+                    combined: None,
+                    total_nanos: 0_f64,
+                }],
+            },
+        }
+    }
+
+    #[test]
+    fn roundtrips_through_json() {
+        let original = run(1_733_029_200, 117.2);
+        let line = tinyjson::JsonValue::from(&original).stringify().unwrap();
+        let parsed: Run = line.parse().unwrap();
+        assert_eq!(parsed.timestamp, original.timestamp);
+        assert_eq!(parsed.commit, original.commit);
+        assert_eq!(parsed.part_nanos(DAY_2, 1), Some(117_200_000_f64));
+    }
+
+    #[test]
+    fn handles_missing_commit() {
+        let parsed: Run = r#"{ "timestamp": 0, "commit": null, "data": [] }"#.parse().unwrap();
+        assert_eq!(parsed.commit, None);
+        assert_eq!(parsed.timings.data.len(), 0);
+    }
+
+    #[test]
+    fn finds_best_run() {
+        let runs = [run(1, 117.2), run(2, 55.5), run(3, 60.0)];
+        let (best_run, nanos) = best(&runs, DAY_2, 1).unwrap();
+        assert_eq!(best_run.timestamp, 2);
+        assert_eq!(nanos, 55_500_000_f64);
+        assert!(best(&runs, DAY_2, 2).is_none());
+        assert!(best(&runs, PuzzleId::new(2025, day!(1)), 1).is_none());
+    }
+
+    #[test]
+    fn finds_best_combined_run() {
+        let mut runs = [run(1, 117.2), run(2, 55.5)];
+        runs[1].timings.data[0].combined = Some(Stats::from_mean(Duration::from_millis(80)));
+        let (best_run, nanos) = best(&runs, DAY_2, COMBINED).unwrap();
+        assert_eq!(best_run.timestamp, 2);
+        assert_eq!(nanos, 80_000_000_f64);
+        assert!(best(&runs, DAY_2, PARSE).is_none());
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_733_029_200), "2024-12-01 05:00");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00");
+    }
+}
@@ -0,0 +1,212 @@
+/// Selects the input a solution binary runs against: the puzzle input by default,
+/// or the input given with `--input <path>`, `--input -` (stdin) or `--example`.
+/// Also discovers the additional inputs of a day, e.g. inputs shared by colleagues.
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::{env, fs, io, process};
+
+use crate::template::{PuzzleId, read_file};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// `data/{year}/inputs/{dd}.txt`.
+    Puzzle,
+    /// `data/{year}/examples/{dd}.txt`.
+    Example,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads the source from the arguments of the current process.
+    pub fn from_env() -> Self {
+        let args: Vec<String> = env::args().collect();
+        Self::from_args(&args).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            process::exit(1);
+        })
+    }
+
+    fn from_args(args: &[String]) -> Result<Self, String> {
+        let is_example = args.iter().any(|x| x == "--example");
+
+        let input = match args.iter().position(|x| x == "--input") {
+            Some(index) => Some(
+                args.get(index + 1)
+                    .ok_or("Unexpected command-line input. Format: cargo solve 1 --input <path>")?,
+            ),
+            None => None,
+        };
+
+        match (input, is_example) {
+            (Some(_), true) => Err("`--input` and `--example` cannot be combined.".into()),
+            (Some(path), false) if path == "-" => Ok(Self::Stdin),
+            (Some(path), false) => Ok(Self::File(path.into())),
+            (None, true) => Ok(Self::Example),
+            (None, false) => Ok(Self::Puzzle),
+        }
+    }
+
+    /// Whether this is the personal puzzle input, i.e. known answers apply and answers may be submitted.
+    pub fn is_puzzle_input(&self) -> bool {
+        *self == Self::Puzzle
+    }
+
+    /// Reads the input of `puzzle` from this source, exiting if it cannot be read.
+    pub fn read(&self, puzzle: PuzzleId) -> String {
+        let result = match self {
+            Self::Puzzle => return read_file("inputs", puzzle),
+            Self::Example => return read_file("examples", puzzle),
+            Self::File(path) => fs::read_to_string(path)
+                .map_err(|e| format!("could not open input file \"{}\": {e}", path.display())),
+            Self::Stdin => io::read_to_string(io::stdin())
+                .map_err(|e| format!("could not read input from stdin: {e}")),
+        };
+
+        result.unwrap_or_else(|e| {
+            eprintln!("{e}");
+            process::exit(1);
+        })
+    }
+}
+
+/// Reads the input selected by the arguments of the current process.
+pub fn read_input(puzzle: PuzzleId) -> String {
+    InputSource::from_env().read(puzzle)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An additional input of a day, stored as `data/{year}/inputs/{dd}/{name}.txt`.
+///
+/// Known answers can be stored next to it in `{name}.answers`, one `<part>: <answer>` per line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExtraInput {
+    pub name: String,
+    pub path: PathBuf,
+    /// Known answers by part.
+    pub answers: BTreeMap<u8, String>,
+}
+
+impl ExtraInput {
+    /// Returns the known answer of a part, if present.
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        self.answers.get(&part).map(String::as_str)
+    }
+}
+
+/// Lists the additional inputs of `puzzle`, sorted by name. Returns no inputs if the directory does not exist.
+pub fn extra_inputs(puzzle: PuzzleId) -> Result<Vec<ExtraInput>, String> {
+    let dir = puzzle.extra_inputs_dir();
+
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(format!("could not read \"{dir}\": {e}")),
+    };
+
+    let mut inputs = vec![];
+
+    for entry in entries {
+        let path = entry.map_err(|e| e.to_string())?.path();
+
+        if path.extension().is_none_or(|extension| extension != "txt") {
+            continue;
+        }
+
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+
+        let answers_path = path.with_extension("answers");
+        let answers = match fs::read_to_string(&answers_path) {
+            Ok(answers) => parse_answers(&answers)
+                .map_err(|e| format!("invalid answers in \"{}\": {e}", answers_path.display()))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => {
+                return Err(format!(
+                    "could not read \"{}\": {e}",
+                    answers_path.display()
+                ));
+            }
+        };
+
+        inputs.push(ExtraInput {
+            name: name.to_string(),
+            path,
+            answers,
+        });
+    }
+
+    inputs.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    Ok(inputs)
+}
+
+/// Parses known answers in the format `<part>: <answer>`, one per line. Blank lines are ignored.
+pub(crate) fn parse_answers(s: &str) -> Result<BTreeMap<u8, String>, String> {
+    s.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (part, answer) = line
+                .split_once(':')
+                .ok_or_else(|| format!("expected \"<part>: <answer>\", found \"{line}\""))?;
+            let part = part
+                .trim()
+                .parse::<u8>()
+                .ok()
+                .filter(|part| matches!(part, 1 | 2))
+                .ok_or_else(|| format!("expected part 1 or 2, found \"{}\"", part.trim()))?;
+            Ok((part, answer.trim().to_string()))
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{InputSource, parse_answers};
+
+    fn parse(args: &[&str]) -> Result<InputSource, String> {
+        let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+        InputSource::from_args(&args)
+    }
+
+    #[test]
+    fn parses_input_sources() {
+        assert_eq!(parse(&["2025_06"]), Ok(InputSource::Puzzle));
+        assert_eq!(parse(&["2025_06", "--example"]), Ok(InputSource::Example));
+        assert_eq!(parse(&["2025_06", "--input", "-"]), Ok(InputSource::Stdin));
+        assert_eq!(
+            parse(&["2025_06", "--time", "--input", "edge.txt"]),
+            Ok(InputSource::File("edge.txt".into()))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_input_sources() {
+        assert!(parse(&["2025_06", "--input"]).is_err());
+        assert!(parse(&["2025_06", "--input", "a.txt", "--example"]).is_err());
+    }
+
+    #[test]
+    fn parses_answers() {
+        assert_eq!(
+            parse_answers("1: 4277556\n\n2:  3263827 \n"),
+            Ok(BTreeMap::from([
+                (1, "4277556".into()),
+                (2, "3263827".into())
+            ]))
+        );
+        assert_eq!(
+            parse_answers("2: abc"),
+            Ok(BTreeMap::from([(2, "abc".into())]))
+        );
+        assert_eq!(parse_answers(""), Ok(BTreeMap::new()));
+        assert!(parse_answers("4277556").is_err());
+        assert!(parse_answers("3: 42").is_err());
+    }
+}
@@ -1,68 +1,340 @@
 use std::{env, fs};
 
+pub mod answers;
 pub mod aoc_cli;
+#[cfg(feature = "client")]
+pub mod aoc_client;
 pub mod commands;
+pub mod examples;
+pub mod input;
+pub mod ocr;
+pub mod record;
+pub mod registry;
 pub mod runner;
+pub mod submission;
 
 pub use day::*;
+pub use puzzle::*;
+pub use selection::*;
+pub use solution::*;
 
+/// The backend used to talk to the Advent of Code website.
+#[cfg(not(feature = "client"))]
+pub use aoc_cli as aoc;
+#[cfg(feature = "client")]
+pub use aoc_client as aoc;
+
+mod comparison;
 mod day;
+mod history;
+mod puzzle;
 mod readme_benchmarks;
 mod run_multi;
+mod selection;
+mod solution;
+mod stats;
 mod timings;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd.join(puzzle.data_path(folder, "txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
         .join("data")
+        .join(puzzle.year.to_string())
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(format!("{}-{part}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `DAY` and `PUZZLE` and sets up the input and runner for each part.
+///
+/// The first two parameters are the year and day of the puzzle.
+/// The optional, third parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// A `parse` function and its output type can be passed to parse the input once and share its output between both parts,
+/// which then take a reference to the parsed input. The parse phase is timed separately from the parts:
+///
+/// ```ignore
+/// advent_of_code::solution!(2025, 5, parse: Kitchen::from => Kitchen);
+///
+/// pub fn part_one(kitchen: &Kitchen) -> Option<usize> { ... }
+/// ```
+///
+/// A solution that computes both answers in one pass can be passed as `combined`, returning a tuple of both parts.
+/// The call is timed as a whole and both answers are checked and submitted like separate parts:
+///
+/// ```ignore
+/// advent_of_code::solution!(2025, 7, combined: solve);
+///
+/// pub fn solve(input: &str) -> (Option<usize>, Option<usize>) { ... }
+/// ```
+///
+/// Additional implementations of a part can be registered as named variants,
+/// which are checked against the main solution's answer and benched alongside it with `--time`:
+///
+/// ```ignore
+/// advent_of_code::solution!(2025, 2, variants: [(1, "ascii", part_one_ascii)]);
+/// ```
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The solution is also exposed as the type `Solver`, which implements [`Solution`].
+/// The macro cannot name the return types of the parts on its own, so `Solver`'s parts return their answers as a [`TextAnswer`]
+/// unless the return types are passed as `answers`, or as `answer` for a single part:
+///
+/// ```ignore
+/// advent_of_code::solution!(2025, 5, parse: Kitchen::from => Kitchen, answers: (Option<usize>, Option<usize>));
+/// advent_of_code::solution!(2025, 7, combined: solve, answers: (Option<usize>, Option<usize>));
+/// advent_of_code::solution!(2025, 8, 1, answer: Result<u64, String>);
+/// ```
+///
+/// The macro also generates a test that checks every part and variant against the examples listed in the manifest
+/// `data/{year}/examples/{dd}.manifest`, see [`examples`].
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
-    };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
-    };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
-    };
-
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $year:expr, $day:expr, $parse:tt, $combined:tt, [$($missing:tt)*], $( [$func:expr, $part:tt $(=> $answer:ty)?] )*; $( ($vpart:expr, $vname:expr, $vfunc:expr) ),* $(,)?) => {
         /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        const DAY: $crate::template::Day = $crate::day!($year, $day);
+
+        /// The puzzle of the current day.
+        const PUZZLE: $crate::template::PuzzleId = $crate::template::PuzzleId::new($year, DAY);
+
+        /// This day's solution, callable as a library through [`Solution`]($crate::template::Solution).
+        pub struct Solver;
 
-        #[cfg(feature = "dhat-heap")]
+        impl $crate::template::Solution for Solver {
+            const PUZZLE: $crate::template::PuzzleId = PUZZLE;
+
+            $crate::solution!(@solution_parse $parse);
+            $( $crate::solution!(@solution_part $part, $func $(=> $answer)?); )*
+            $( $crate::solution!(@solution_missing $missing); )*
+            $crate::solution!(@solution_combined $combined);
+        }
+
+        /// Entry point used to run this day in-process.
+        pub const SOLUTION: $crate::template::registry::DaySolution =
+            $crate::template::registry::DaySolution {
+                puzzle: PUZZLE,
+                run: |input, is_timed| {
+                    use $crate::template::runner::*;
+                    #[allow(unused_mut)]
+                    let mut records = vec![];
+                    $crate::solution!(@parse $parse, input, records, measure_parse, is_timed);
+                    $crate::solution!(@combined $combined, input, records, measure_combined, is_timed);
+                    records.extend([$( measure_part($func, input, PUZZLE, $part, is_timed) ),*]);
+                    records
+                },
+            };
+
+        /// Runs every part and variant against the examples listed in `data/{year}/examples/{dd}.manifest`.
+        #[test]
+        fn examples_match_manifest() {
+            use $crate::template::{examples::*, runner::PartOutput};
+            let parts = [
+                $( CheckedPart {
+                    part: $part,
+                    name: None,
+                    run: &|input: &str| {
+                        let input = $crate::solution!(@parsed $parse, input);
+                        $func(input).into_answer().map(|a| a.map(|a| a.to_string()))
+                    },
+                }, )*
+                $( CheckedPart {
+                    part: $vpart,
+                    name: Some($vname),
+                    run: &|input: &str| {
+                        let input = $crate::solution!(@parsed $parse, input);
+                        $vfunc(input).into_answer().map(|a| a.map(|a| a.to_string()))
+                    },
+                }, )*
+            ];
+            let combined = $crate::solution!(@checked $combined, $parse);
+            check_examples(PUZZLE, &parts.into_iter().chain(combined).collect::<Vec<_>>());
+        }
+
+        // NOTE: the registry compiles every solution into a single binary, which can only have one allocator.
+        #[cfg(all(feature = "dhat-heap", not(feature = "registry")))]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let input = $crate::template::input::read_input(PUZZLE);
+            let input = input.as_str();
+
+            #[allow(unused_mut)]
+            let mut records = vec![];
+            $crate::solution!(@parse $parse, input, records, run_parse);
+            $crate::solution!(@combined $combined, input, records, run_combined);
+            records.extend([$( run_part($func, input, PUZZLE, $part) ),*]);
+
+            let variants: Vec<VariantRecord> = std::iter::empty()
+                $( .chain(run_variant($vfunc, input, PUZZLE, $vpart, $vname, &records)) )*
+                .collect();
+            finish(&records, &variants);
+        }
+    };
+
+    // runs the parse phase, if any, shadowing `$input` with its output.
+    (@parse [], $input:ident, $records:ident, $run:ident $(, $arg:expr)*) => {};
+    (@parse [$parse:expr => $parsed:ty], $input:ident, $records:ident, $run:ident $(, $arg:expr)*) => {
+        let (parsed, record) = $run($parse, $input, PUZZLE $(, $arg)*);
+        $records.push(record);
+        let $input = &parsed;
+    };
+
+    // the input of the parts, without recording the parse phase.
+    (@parsed [], $input:ident) => { $input };
+    (@parsed [$parse:expr => $parsed:ty], $input:ident) => { &$parse($input) };
+
+    // the items of the `Solution` implementation.
+    (@solution_parse []) => {
+        type Parsed<'a> = &'a str;
+
+        fn parse(input: &str) -> &str {
+            input
+        }
+    };
+    (@solution_parse [$parse:expr => $parsed:ty]) => {
+        type Parsed<'a> = $parsed;
+
+        fn parse(input: &str) -> Self::Parsed<'_> {
+            $parse(input)
+        }
+    };
+    // NOTE: the answer types are only known when passed along with the functions, e.g. as `answers` or by `#[aoc::solution]`.
+    // Otherwise the parts return their answers as text.
+    (@solution_part 1, $func:expr => $answer:ty) => {
+        type Part1 = $answer;
+
+        fn part_one(parsed: &Self::Parsed<'_>) -> $answer {
+            $func(parsed)
         }
     };
+    (@solution_part 1, $func:expr) => {
+        type Part1 = $crate::template::TextAnswer;
+
+        fn part_one(parsed: &Self::Parsed<'_>) -> $crate::template::TextAnswer {
+            $crate::template::TextAnswer::of($func(parsed))
+        }
+    };
+    (@solution_part 2, $func:expr => $answer:ty) => {
+        type Part2 = $answer;
+
+        fn part_two(parsed: &Self::Parsed<'_>) -> $answer {
+            $func(parsed)
+        }
+    };
+    (@solution_part 2, $func:expr) => {
+        type Part2 = $crate::template::TextAnswer;
+
+        fn part_two(parsed: &Self::Parsed<'_>) -> $crate::template::TextAnswer {
+            $crate::template::TextAnswer::of($func(parsed))
+        }
+    };
+    (@solution_missing 1) => {
+        type Part1 = $crate::template::TextAnswer;
+
+        fn part_one(_parsed: &Self::Parsed<'_>) -> $crate::template::TextAnswer {
+            $crate::template::TextAnswer(Ok(None))
+        }
+    };
+    (@solution_missing 2) => {
+        type Part2 = $crate::template::TextAnswer;
+
+        fn part_two(_parsed: &Self::Parsed<'_>) -> $crate::template::TextAnswer {
+            $crate::template::TextAnswer(Ok(None))
+        }
+    };
+    (@solution_combined []) => {};
+    (@solution_combined [$solve:expr => $part_1:ty, $part_2:ty]) => {
+        type Part1 = $part_1;
+        type Part2 = $part_2;
+
+        fn part_one(parsed: &Self::Parsed<'_>) -> $part_1 {
+            $solve(parsed).0
+        }
+
+        fn part_two(parsed: &Self::Parsed<'_>) -> $part_2 {
+            $solve(parsed).1
+        }
+
+        fn solve(input: &str) -> ($part_1, $part_2) {
+            $solve(&Self::parse(input))
+        }
+    };
+    (@solution_combined [$solve:expr]) => {
+        type Part1 = $crate::template::TextAnswer;
+        type Part2 = $crate::template::TextAnswer;
+
+        fn part_one(parsed: &Self::Parsed<'_>) -> $crate::template::TextAnswer {
+            $crate::template::TextAnswer::of($solve(parsed).0)
+        }
+
+        fn part_two(parsed: &Self::Parsed<'_>) -> $crate::template::TextAnswer {
+            $crate::template::TextAnswer::of($solve(parsed).1)
+        }
+
+        fn solve(input: &str) -> ($crate::template::TextAnswer, $crate::template::TextAnswer) {
+            let (part_1, part_2) = $solve(&Self::parse(input));
+            ($crate::template::TextAnswer::of(part_1), $crate::template::TextAnswer::of(part_2))
+        }
+    };
+
+    // runs a solution that computes both parts in one call, if any.
+    (@combined [], $input:ident, $records:ident, $run:ident $(, $arg:expr)*) => {};
+    (@combined [$solve:expr $(=> $part_1:ty, $part_2:ty)?], $input:ident, $records:ident, $run:ident $(, $arg:expr)*) => {
+        $records.extend($run($solve, $input, PUZZLE $(, $arg)*));
+    };
+
+    // the example checks of both parts of a combined solution.
+    (@checked [], $parse:tt) => { [] };
+    (@checked [$solve:expr $(=> $part_1:ty, $part_2:ty)?], $parse:tt) => {
+        [
+            CheckedPart {
+                part: 1,
+                name: None,
+                run: &|input: &str| {
+                    let input = $crate::solution!(@parsed $parse, input);
+                    $solve(input).0.into_answer().map(|a| a.map(|a| a.to_string()))
+                },
+            },
+            CheckedPart {
+                part: 2,
+                name: None,
+                run: &|input: &str| {
+                    let input = $crate::solution!(@parsed $parse, input);
+                    $solve(input).1.into_answer().map(|a| a.map(|a| a.to_string()))
+                },
+            },
+        ]
+    };
+
+    ($year:expr, $day:expr $(, parse: $parse:expr => $parsed:ty)?, combined: $solve:expr $(, answers: ($part_1:ty, $part_2:ty))?) => {
+        $crate::solution!(@impl $year, $day, [$($parse => $parsed)?], [$solve $(=> $part_1, $part_2)?], [], ;);
+    };
+    ($year:expr, $day:expr $(, parse: $parse:expr => $parsed:ty)? $(, answers: ($part_1:ty, $part_2:ty))? $(, variants: [$($variants:tt)*])?) => {
+        $crate::solution!(@impl $year, $day, [$($parse => $parsed)?], [], [], [part_one, 1 $(=> $part_1)?] [part_two, 2 $(=> $part_2)?]; $($($variants)*)?);
+    };
+    ($year:expr, $day:expr, 1 $(, parse: $parse:expr => $parsed:ty)? $(, answer: $answer:ty)? $(, variants: [$($variants:tt)*])?) => {
+        $crate::solution!(@impl $year, $day, [$($parse => $parsed)?], [], [2], [part_one, 1 $(=> $answer)?]; $($($variants)*)?);
+    };
+    ($year:expr, $day:expr, 2 $(, parse: $parse:expr => $parsed:ty)? $(, answer: $answer:ty)? $(, variants: [$($variants:tt)*])?) => {
+        $crate::solution!(@impl $year, $day, [$($parse => $parsed)?], [], [1], [part_two, 2 $(=> $answer)?]; $($($variants)*)?);
+    };
 }
@@ -0,0 +1,298 @@
+/// Recognises capital letters drawn by solutions in the fonts used by Advent of Code puzzles,
+/// so that answers rendered as ASCII art can be checked and submitted like any other answer.
+use std::borrow::Cow;
+
+/// Glyphs of the 4x6 font, e.g. from 2016 day 8 or 2022 day 10. Some letters are narrower or wider than 4 columns.
+const FONT_4X6: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Glyphs of the 6x10 font, e.g. from 2018 day 10.
+const FONT_6X10: &[(char, [&str; 10])] = &[
+    (
+        'A',
+        [
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        [
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        [
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        [
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        [
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        [
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        [
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        [
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+/// Whether a character of the art is a lit pixel. Solutions commonly draw with `#` or `█`.
+fn is_lit(c: char) -> bool {
+    matches!(c, '#' | '█')
+}
+
+/// Recognises the letters drawn in `art`, a grid of lit (`#` or `█`) and unlit pixels, e.g. `.` or spaces.
+/// Returns `None` unless the art is 6 or 10 rows high and every glyph is a letter of the matching font.
+pub fn recognize(art: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = art
+        .lines()
+        .map(|line| line.chars().map(is_lit).collect())
+        .skip_while(|row: &Vec<bool>| !row.contains(&true))
+        .collect();
+    let height = rows.len()
+        - rows
+            .iter()
+            .rev()
+            .take_while(|row| !row.contains(&true))
+            .count();
+    let rows = &rows[..height];
+
+    let width = rows.iter().map(Vec::len).max()?;
+    let is_column_lit = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+
+    // NOTE: glyphs are separated by unlit columns, which none of the letters contain.
+    let mut glyphs = vec![];
+    let mut x = 0;
+    while x < width {
+        if !is_column_lit(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && is_column_lit(x) {
+            x += 1;
+        }
+
+        let glyph: Vec<String> = rows
+            .iter()
+            .map(|row| {
+                (start..x)
+                    .map(|x| if row.get(x) == Some(&true) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        glyphs.push(glyph);
+    }
+
+    if glyphs.is_empty() {
+        return None;
+    }
+
+    glyphs
+        .iter()
+        .map(|glyph| match height {
+            6 => find_letter(FONT_4X6, glyph),
+            10 => find_letter(FONT_6X10, glyph),
+            _ => None,
+        })
+        .collect()
+}
+
+fn find_letter<const H: usize>(font: &[(char, [&str; H])], glyph: &[String]) -> Option<char> {
+    font.iter()
+        .find(|(_, rows)| {
+            // NOTE: the fonts are stored with their unlit edge columns, the glyph was cut from the art without them.
+            let offset = (0..rows[0].len())
+                .find(|&x| rows.iter().any(|row| row.as_bytes()[x] == b'#'))
+                .unwrap_or(0);
+            rows.iter().zip(glyph).all(|(row, glyph_row)| {
+                row[offset..].trim_end_matches('.') == glyph_row.trim_end_matches('.')
+            })
+        })
+        .map(|(letter, _)| *letter)
+}
+
+/// The text of an answer as submitted: the recognised letters of multi-line answers, the answer itself otherwise.
+pub fn answer_text(answer: &str) -> Cow<'_, str> {
+    if !answer.contains('\n') {
+        return Cow::Borrowed(answer);
+    }
+
+    match recognize(answer) {
+        Some(letters) => Cow::Owned(letters),
+        None => Cow::Borrowed(answer),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{answer_text, recognize};
+
+    #[test]
+    fn recognizes_small_letters() {
+        let art = [
+            "#..#.###...##..####.###..#..#.#..#..##..",
+            "#..#.#..#.#..#.#....#..#.#..#.#.#..#..#.",
+            "####.###..#....###..#..#.####.##...#..#.",
+            "#..#.#..#.#....#....###..#..#.#.#..####.",
+            "#..#.#..#.#..#.#....#.#..#..#.#.#..#..#.",
+            "#..#.###...##..####.#..#.#..#.#..#.#..#.",
+        ]
+        .join("\n");
+        assert_eq!(recognize(&art), Some("HBCERHKA".into()));
+    }
+
+    #[test]
+    fn recognizes_narrow_and_wide_letters() {
+        let art = [
+            "\n",
+            "█   █  ███ ",
+            "█   █   █  ",
+            " █ █    █  ",
+            "  █     █  ",
+            "  █     █  ",
+            "  █    ███ ",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(recognize(&art), Some("YI".into()));
+    }
+
+    #[test]
+    fn recognizes_large_letters() {
+        let art = [
+            "#....#..######",
+            "#....#..#.....",
+            ".#..#...#.....",
+            ".#..#...#.....",
+            "..##....#####.",
+            "..##....#.....",
+            ".#..#...#.....",
+            ".#..#...#.....",
+            "#....#..#.....",
+            "#....#..######",
+        ]
+        .join("\n");
+        assert_eq!(recognize(&art), Some("XE".into()));
+    }
+
+    #[test]
+    fn rejects_unknown_glyphs() {
+        assert_eq!(recognize("#.#\n.#.\n#.#"), None);
+        assert_eq!(recognize("...\n..."), None);
+        assert_eq!(
+            recognize(&["####", "#..#", "#..#", "#..#", "#..#", "####"].join("\n")),
+            None
+        );
+    }
+
+    #[test]
+    fn returns_the_text_of_answers() {
+        assert_eq!(answer_text("1234"), "1234");
+        assert_eq!(answer_text("#.#\n.#."), "#.#\n.#.");
+        assert_eq!(
+            answer_text(&["####", "#...", "###.", "#...", "#...", "#..."].join("\n")),
+            "F"
+        );
+    }
+}
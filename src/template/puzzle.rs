@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Display;
+use std::str::FromStr;
+use tinyjson::JsonValue;
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+use crate::template::Day;
+
+#[cfg(feature = "today")]
+const SERVER_UTC_OFFSET: i32 = -5;
+
+/// Identifies the puzzle of a day in a given year of advent.
+///
+/// # Display
+/// This value displays as the year and two digit day.
+///
+/// ```
+/// # use advent_of_code::template::{Day, PuzzleId};
+/// let puzzle = PuzzleId::new(2024, Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2024/08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: u16,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: u16, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Name of the solution binary, e.g. `2024_08`.
+    pub fn bin_name(&self) -> String {
+        format!("{}_{}", self.year, self.day)
+    }
+
+    /// Path of the solution binary's module, e.g. `./src/bin/2024_08.rs`.
+    pub fn bin_path(&self) -> String {
+        format!("./src/bin/{}.rs", self.bin_name())
+    }
+
+    /// Path of a data file in `folder`, e.g. `data/2024/inputs/08.txt`.
+    pub fn data_path(&self, folder: &str, extension: &str) -> String {
+        format!("data/{}/{folder}/{}.{extension}", self.year, self.day)
+    }
+
+    pub fn input_path(&self) -> String {
+        self.data_path("inputs", "txt")
+    }
+
+    pub fn example_path(&self) -> String {
+        self.data_path("examples", "txt")
+    }
+
+    pub fn puzzle_path(&self) -> String {
+        self.data_path("puzzles", "md")
+    }
+
+    /// Directory of additional inputs, e.g. `data/2024/inputs/08`.
+    pub fn extra_inputs_dir(&self) -> String {
+        format!("data/{}/inputs/{}", self.year, self.day)
+    }
+}
+
+#[cfg(feature = "today")]
+impl PuzzleId {
+    /// Returns the puzzle of the current day if it's part of this year's calendar, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        if today.month() != 12 {
+            return None;
+        }
+        let year = u16::try_from(today.year()).ok()?;
+        let day = Day::new_in(year, u8::try_from(today.day()).ok()?)?;
+        Some(Self::new(year, day))
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+/// The year used when no year is passed to a command, read from `AOC_YEAR`.
+pub fn default_year() -> Option<u16> {
+    env::var("AOC_YEAR").ok()?.parse().ok()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Nests values of puzzle parts in a JSON object keyed by year, day and part,
+/// e.g. `{ "2025": { "01": { "part_1": ... } } }`.
+pub(crate) fn to_nested_json(
+    values: impl IntoIterator<Item = (PuzzleId, u8, JsonValue)>,
+) -> JsonValue {
+    let mut years: HashMap<String, HashMap<String, HashMap<String, JsonValue>>> = HashMap::new();
+
+    for (puzzle, part, value) in values {
+        years
+            .entry(puzzle.year.to_string())
+            .or_default()
+            .entry(puzzle.day.to_string())
+            .or_default()
+            .insert(format!("part_{part}"), value);
+    }
+
+    JsonValue::Object(
+        years
+            .into_iter()
+            .map(|(year, days)| {
+                let days = days
+                    .into_iter()
+                    .map(|(day, parts)| (day, JsonValue::Object(parts)))
+                    .collect();
+                (year, JsonValue::Object(days))
+            })
+            .collect(),
+    )
+}
+
+/// Reads the values of puzzle parts from a JSON object created by [`to_nested_json`].
+/// `name` describes the document in error messages.
+pub(crate) fn from_nested_json<'a>(
+    value: &'a JsonValue,
+    name: &str,
+) -> Result<Vec<(PuzzleId, u8, &'a JsonValue)>, String> {
+    let object = |value: &'a JsonValue, path: &str| {
+        value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or_else(|| format!("Expected {path} to be an object."))
+    };
+
+    let mut values = vec![];
+
+    for (year, days) in object(value, name)? {
+        let year = year
+            .parse::<u16>()
+            .or(Err(format!("Expected {name} keys to be years.")))?;
+
+        for (day, parts) in object(days, &format!("{name}.year"))? {
+            let day =
+                Day::from_str(day).or(Err(format!("Expected {name}.year keys to be days.")))?;
+
+            for (part, value) in object(parts, &format!("{name}.year.day"))? {
+                let part = part
+                    .strip_prefix("part_")
+                    .and_then(|x| x.parse::<u8>().ok())
+                    .ok_or(format!("Expected {name}.year.day keys to be parts."))?;
+
+                values.push((PuzzleId::new(year, day), part, value));
+            }
+        }
+    }
+
+    values.sort_unstable_by_key(|(puzzle, part, _)| (*puzzle, *part));
+    Ok(values)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{PuzzleId, from_nested_json, to_nested_json};
+    use crate::day;
+
+    #[test]
+    fn builds_paths() {
+        let puzzle = PuzzleId::new(2024, day!(8));
+        assert_eq!(puzzle.bin_name(), "2024_08");
+        assert_eq!(puzzle.bin_path(), "./src/bin/2024_08.rs");
+        assert_eq!(puzzle.input_path(), "data/2024/inputs/08.txt");
+        assert_eq!(puzzle.puzzle_path(), "data/2024/puzzles/08.md");
+    }
+
+    #[test]
+    fn roundtrips_nested_json() {
+        let values = vec![
+            (PuzzleId::new(2024, day!(8)), 1, JsonValue::Number(1.0)),
+            (PuzzleId::new(2024, day!(8)), 2, JsonValue::Null),
+            (
+                PuzzleId::new(2025, day!(1)),
+                1,
+                JsonValue::String("a".into()),
+            ),
+        ];
+
+        let json = to_nested_json(values.clone());
+        let parsed: Vec<_> = from_nested_json(&json, "test")
+            .unwrap()
+            .into_iter()
+            .map(|(puzzle, part, value)| (puzzle, part, value.clone()))
+            .collect();
+
+        assert_eq!(parsed, values);
+        assert!(from_nested_json(&JsonValue::Null, "test").is_err());
+    }
+}
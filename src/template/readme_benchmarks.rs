@@ -2,8 +2,8 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
-use crate::template::timings::Timings;
+use crate::template::record::{COMBINED, PARSE};
+use crate::template::timings::{Timings, format_nanos};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -25,11 +25,6 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     let matches: Vec<_> = readme.match_indices(MARKER).collect();
 
@@ -52,38 +47,70 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+fn construct_table(prefix: &str, timings: Timings) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
-
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    let mut lines: Vec<String> = vec![MARKER.into(), header];
+
+    let mut years: Vec<u16> = timings.data.iter().map(|t| t.puzzle.year).collect();
+    years.dedup();
+
+    // NOTE: timings are sorted by puzzle, i.e. grouped by year.
+    for year in years {
+        let year_timings = Timings {
+            data: timings
+                .data
+                .iter()
+                .filter(|t| t.puzzle.year == year)
+                .cloned()
+                .collect(),
+        };
+
+        lines.extend([
+            String::new(),
+            format!("{prefix}# {year}"),
+            String::new(),
+            "| Day | Parse | Part 1 | Part 2 |".into(),
+            "| :---: | :---: | :---: | :---:  |".into(),
+        ]);
+
+        for timing in &year_timings.data {
+            let format_part = |part: u8| {
+                let nanos = timing
+                    .part_nanos(part)
+                    .map_or_else(|| "-".into(), format_nanos);
+                format!("`{nanos}`")
+            };
+
+            // NOTE: both parts of a combined solution are timed as a whole, in the column of part 1.
+            let (part_1, part_2) = match timing.combined {
+                Some(_) => (
+                    format!("{} (combined)", format_part(COMBINED)),
+                    "(combined)".into(),
+                ),
+                None => (format_part(1), format_part(2)),
+            };
+
+            lines.push(format!(
+                "| [Day {}]({}) | {} | {part_1} | {part_2} |",
+                timing.puzzle.day.into_inner(),
+                timing.puzzle.bin_path(),
+                format_part(PARSE),
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push(format!("**Total: {:.2}ms**", year_timings.total_millis()));
     }
 
-    lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
     lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+fn update_content(s: &mut String, timings: Timings) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", timings);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
@@ -91,37 +118,67 @@ fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result
 pub fn update(timings: Timings) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, timings)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
+    use std::time::Duration;
+
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day,
+        template::{
+            PuzzleId,
+            stats::Stats,
+            timings::{Timing, Timings},
+        },
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    puzzle: PuzzleId::new(2025, day!(1)),
+                    parse: None,
+                    part_1: Some(Stats::from_mean(Duration::from_millis(10))),
+                    part_2: Some(Stats::from_mean(Duration::from_millis(20))),
+                    combined: None,
+                    total_nanos: 3e+7,
+                },
+                Timing {
+                    puzzle: PuzzleId::new(2025, day!(2)),
+                    parse: Some(Stats::from_mean(Duration::from_millis(5))),
+                    part_1: Some(Stats::from_mean(Duration::from_millis(30))),
+                    part_2: Some(Stats::from_mean(Duration::from_millis(40))),
+                    combined: None,
+                    total_nanos: 7e+7,
+                },
+                Timing {
+                    puzzle: PuzzleId::new(2025, day!(4)),
+                    parse: None,
+                    part_1: Some(Stats::from_mean(Duration::from_millis(40))),
+                    part_2: Some(Stats::from_mean(Duration::from_millis(50))),
+                    combined: None,
+                    total_nanos: 9e+7,
                 },
                 Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    puzzle: PuzzleId::new(2025, day!(5)),
+                    parse: None,
+                    part_1: None,
+                    part_2: None,
+                    combined: Some(Stats::from_mean(Duration::from_millis(15))),
+                    total_nanos: 1.5e+7,
                 },
                 Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
+                    puzzle: PuzzleId::new(2026, day!(1)),
+                    parse: None,
+                    part_1: Some(Stats::from_mean(Duration::from_millis(1))),
+                    part_2: None,
+                    combined: None,
+                    total_nanos: 1e+6,
                 },
             ],
         }
@@ -131,28 +188,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        update_content(&mut s, get_mock_timings()).unwrap();
+        assert!(s.contains("## Benchmarks"));
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,20 +217,31 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "### 2025",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2025_01.rs) | `-` | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2025_02.rs) | `5.0ms` | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2025_04.rs) | `-` | `40.0ms` | `50.0ms` |",
+            "| [Day 5](./src/bin/2025_05.rs) | `-` | `15.0ms` (combined) | (combined) |",
+            "",
+            "**Total: 205.00ms**",
+            "",
+            "### 2026",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2026_01.rs) | `-` | `1.0ms` | `-` |",
             "",
-            "**Total: 190.00ms**",
+            "**Total: 1.00ms**",
             "<!--- benchmarking table --->",
             "baz",
         ]
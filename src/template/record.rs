@@ -0,0 +1,229 @@
+/// Machine-readable results of running a solution part.
+/// Solution binaries emit one record per line when invoked with `--json`.
+use std::{collections::HashMap, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, PuzzleId, stats::Stats};
+
+/// Part number of the records of a solution's parse phase, which are recorded like a part without an answer.
+pub const PARSE: u8 = 0;
+
+/// Part number of the records of a solution that computes both parts in one call.
+/// The call is timed as a whole, the records of its parts have no samples of their own.
+pub const COMBINED: u8 = 3;
+
+/// Label of a part in human-readable output, e.g. `Part 1` or `Parse`.
+pub fn part_label(part: u8) -> String {
+    match part {
+        PARSE => "Parse".into(),
+        COMBINED => "Combined".into(),
+        part => format!("Part {part}"),
+    }
+}
+
+/// The result of running (and optionally benching) a single solution part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartRecord {
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// The error returned by the part, if it failed.
+    pub error: Option<String>,
+    pub stats: Stats,
+}
+
+impl PartRecord {
+    /// Creates a record from the execution times of each sample.
+    pub fn new(puzzle: PuzzleId, part: u8, answer: Option<String>, timers: &[Duration]) -> Self {
+        Self {
+            puzzle,
+            part,
+            answer,
+            error: None,
+            stats: Stats::from_samples(timers),
+        }
+    }
+
+    /// Marks the record as failed with `error`.
+    #[must_use]
+    pub fn with_error(mut self, error: Option<String>) -> Self {
+        self.error = error;
+        self
+    }
+
+    /// Whether the part returned an error.
+    pub fn is_failed(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Serializes the record to a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        JsonValue::from(self)
+            .stringify()
+            .expect("part record should be serializable")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartRecord> for JsonValue {
+    fn from(value: &PartRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::Number(f64::from(value.puzzle.year)),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::String(value.puzzle.day.to_string()),
+        );
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        if let Some(error) = &value.error {
+            map.insert("error".into(), JsonValue::String(error.clone()));
+        }
+        value.stats.write_json(&mut map);
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartRecord {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<f64>())
+            .map(|year| *year as u16)
+            .ok_or("Expected record.year to be a number.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected record.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .ok_or("Expected record.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.answer to be null or string.")?;
+
+        let error = match json.get("error") {
+            None | Some(JsonValue::Null) => None,
+            Some(JsonValue::String(error)) => Some(error.clone()),
+            Some(_) => return Err("Expected record.error to be null or string.".into()),
+        };
+
+        let stats = Stats::read_json(json).map_err(|e| format!("Invalid record stats: {e}"))?;
+
+        Ok(PartRecord {
+            puzzle: PuzzleId::new(year, day),
+            part,
+            answer: answer.cloned(),
+            error,
+            stats,
+        })
+    }
+}
+
+impl FromStr for PartRecord {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not a valid JSON record."))?;
+        PartRecord::try_from(&json)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::PartRecord;
+    use crate::{day, template::PuzzleId};
+
+    #[test]
+    fn computes_sample_statistics() {
+        let record = PartRecord::new(
+            PuzzleId::new(2025, day!(1)),
+            1,
+            Some("42".into()),
+            &[
+                Duration::from_nanos(10),
+                Duration::from_nanos(30),
+                Duration::from_nanos(20),
+            ],
+        );
+        assert_eq!(record.stats.mean, Duration::from_nanos(20));
+        assert_eq!(record.stats.samples, 3);
+        assert_eq!(record.stats.min, Duration::from_nanos(10));
+        assert_eq!(record.stats.max, Duration::from_nanos(30));
+    }
+
+    #[test]
+    fn roundtrips_through_json() {
+        let record = PartRecord::new(
+            PuzzleId::new(2025, day!(6)),
+            2,
+            Some("Part 1: @ ( 2 samples)\nok".into()),
+            &[Duration::from_micros(74), Duration::from_micros(76)],
+        );
+        let parsed: PartRecord = record.to_json_line().parse().unwrap();
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn roundtrips_errors_through_json() {
+        let record = PartRecord::new(PuzzleId::new(2025, day!(1)), 1, None, &[Duration::ZERO])
+            .with_error(Some("unexpected direction \"X\"".into()));
+        let parsed: PartRecord = record.to_json_line().parse().unwrap();
+        assert!(parsed.is_failed());
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn handles_missing_answers() {
+        let parsed: PartRecord = r#"{ "year": 2024, "day": "03", "part": 1, "answer": null, "nanos": 0, "samples": 1, "min_nanos": 0, "max_nanos": 0 }"#
+            .parse()
+            .unwrap();
+        assert_eq!(parsed.puzzle, PuzzleId::new(2024, day!(3)));
+        assert_eq!(parsed.answer, None);
+        assert!(!parsed.is_failed());
+    }
+
+    #[test]
+    fn rejects_non_records() {
+        assert!(
+            "Part 1: 42 (1.2ms @ 100 samples)"
+                .parse::<PartRecord>()
+                .is_err()
+        );
+        assert!(r#"{ "day": "03" }"#.parse::<PartRecord>().is_err());
+        assert!(
+            r#"{ "day": "03", "part": 1, "answer": null, "nanos": 0, "samples": 1 }"#
+                .parse::<PartRecord>()
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,27 @@
+/// Lookup of solutions that can be run in-process, without invoking `cargo run` per day.
+/// The registry is populated from `src/bin` when the `registry` feature is enabled and empty otherwise.
+use crate::template::{PuzzleId, record::PartRecord};
+
+/// Entry point of a single day's solution, created by the `solution!` macro.
+#[derive(Clone, Copy)]
+pub struct DaySolution {
+    pub puzzle: PuzzleId,
+    /// Runs every part of the solution against `input`, benching them if `is_timed` is set.
+    pub run: fn(input: &str, is_timed: bool) -> Vec<PartRecord>,
+}
+
+/// All solutions compiled into this binary.
+pub fn solutions() -> &'static [DaySolution] {
+    #[cfg(feature = "registry")]
+    return crate::days::SOLUTIONS;
+
+    #[cfg(not(feature = "registry"))]
+    return &[];
+}
+
+/// Returns the solution for `puzzle` if it is compiled into this binary.
+pub fn find(puzzle: PuzzleId) -> Option<&'static DaySolution> {
+    solutions()
+        .iter()
+        .find(|solution| solution.puzzle == puzzle)
+}
@@ -1,55 +1,521 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Display,
+    fs, io,
+    num::NonZero,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, PuzzleId};
 
 use super::{
-    all_days,
+    answers::{Answers, Verdict},
+    record::{COMBINED, PARSE, PartRecord},
+    registry::{self, DaySolution},
+    runner::{format_stats, print_failure, print_result, print_timing},
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// The outcome of running a set of days.
+pub struct MultiRun {
+    /// Benchmark timings, if the days were timed.
+    pub timings: Option<Timings>,
+    /// Number of parts whose answer does not match the known-correct answer.
+    pub mismatches: usize,
+    /// The status of every puzzle that was run.
+    pub statuses: Vec<(PuzzleId, Status)>,
+}
+
+impl MultiRun {
+    /// Puzzles that failed, crashed or timed out.
+    pub fn failures(&self) -> impl Iterator<Item = &(PuzzleId, Status)> {
+        self.statuses
+            .iter()
+            .filter(|(_, status)| status.is_failure())
+    }
+
+    /// Prints a summary of unsuccessful puzzles and mismatched answers and exits with an error if there were any.
+    pub fn exit_on_failure(&self) {
+        let failures: Vec<_> = self
+            .failures()
+            .map(|(puzzle, status)| format!("{puzzle} ({status})"))
+            .collect();
+
+        if !failures.is_empty() {
+            eprintln!(
+                "\n{} day(s) did not succeed: {}",
+                failures.len(),
+                failures.join(", ")
+            );
+        }
+
+        if self.mismatches > 0 {
+            eprintln!(
+                "\n{} answer(s) do not match the known-correct answers.",
+                self.mismatches
+            );
+        }
+
+        if !failures.is_empty() || self.mismatches > 0 {
+            process::exit(1);
+        }
+    }
+}
+
+/// How a solution terminated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Exit {
+    Success,
+    /// The solution panicked or exited with a non-zero status, if known.
+    Crashed(Option<i32>),
+    /// The solution was stopped after exceeding the timeout.
+    TimedOut(Duration),
+}
+
+/// How far a puzzle got in a run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    /// No part returned an answer.
+    Unsolved,
+    /// A part returned an error.
+    Failed,
+    Crashed(Option<i32>),
+    TimedOut(Duration),
+}
+
+impl Status {
+    pub fn of(records: &[PartRecord], exit: Exit) -> Self {
+        match exit {
+            Exit::Crashed(code) => Status::Crashed(code),
+            Exit::TimedOut(timeout) => Status::TimedOut(timeout),
+            Exit::Success if records.iter().any(PartRecord::is_failed) => Status::Failed,
+            Exit::Success if records.iter().any(|r| r.answer.is_some()) => Status::Solved,
+            Exit::Success => Status::Unsolved,
+        }
+    }
+
+    /// Whether the puzzle did not run to completion or a part returned an error.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Status::Failed | Status::Crashed(_) | Status::TimedOut(_)
+        )
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Solved => write!(f, "solved"),
+            Status::Unsolved => write!(f, "unsolved"),
+            Status::Failed => write!(f, "failed"),
+            Status::Crashed(Some(code)) => write!(f, "crashed with exit code {code}"),
+            Status::Crashed(None) => write!(f, "crashed"),
+            Status::TimedOut(timeout) => write!(f, "timed out after {timeout:.1?}"),
+        }
+    }
+}
+
+/// A line a solution printed besides its records.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OutputLine {
+    Stdout(String),
+    Stderr(String),
+}
+
+/// Where the output of a solution goes: forwarded immediately, or buffered until its day is reported.
+#[derive(Clone, Default)]
+pub struct Output {
+    buffer: Option<Arc<Mutex<Vec<OutputLine>>>>,
+}
+
+impl Output {
+    pub fn forwarded() -> Self {
+        Self { buffer: None }
+    }
+
+    fn buffered() -> Self {
+        Self {
+            buffer: Some(Arc::default()),
+        }
+    }
+
+    pub fn println(&self, line: String) {
+        match &self.buffer {
+            Some(buffer) => buffer.lock().unwrap().push(OutputLine::Stdout(line)),
+            None => println!("{line}"),
+        }
+    }
+
+    pub fn eprintln(&self, line: String) {
+        match &self.buffer {
+            Some(buffer) => buffer.lock().unwrap().push(OutputLine::Stderr(line)),
+            None => eprintln!("{line}"),
+        }
+    }
+
+    /// Takes the buffered lines, empty if the output was forwarded.
+    fn take_lines(&self) -> Vec<OutputLine> {
+        self.buffer
+            .as_ref()
+            .map(|buffer| std::mem::take(&mut *buffer.lock().unwrap()))
+            .unwrap_or_default()
+    }
+}
+
+/// The result of running the solution of a single day.
+struct DayRun {
+    records: Vec<PartRecord>,
+    exit: Exit,
+    /// Output that was buffered while the day ran.
+    output: Vec<OutputLine>,
+}
+
+/// Runs the selected puzzles and reports them in order.
+/// With `is_parallel`, days run concurrently with their output buffered. This must not be combined with `is_timed`,
+/// concurrent days would skew each other's measurements.
+pub fn run_multi(
+    puzzles_to_run: &HashSet<PuzzleId>,
+    is_release: bool,
+    is_timed: bool,
+    is_parallel: bool,
+    timeout: Option<Duration>,
+) -> MultiRun {
+    debug_assert!(!(is_timed && is_parallel), "timed runs must be sequential");
+
+    let mut timings: Vec<Timing> = Vec::with_capacity(puzzles_to_run.len());
+    let mut mismatches = 0;
+    let mut statuses = vec![];
+
+    let answers = Answers::read_from_file();
 
     let mut need_space = false;
+    let mut print_heading = |puzzle: PuzzleId| {
+        if need_space {
+            println!();
+        }
+        need_space = true;
+
+        println!("{ANSI_BOLD}{} Day {}{ANSI_RESET}", puzzle.year, puzzle.day);
+        println!("-----------");
+    };
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
+    let mut report = |puzzle: PuzzleId, run: DayRun| {
+        for line in run.output {
+            match line {
+                OutputLine::Stdout(line) => println!("{line}"),
+                OutputLine::Stderr(line) => eprintln!("{line}"),
             }
-            need_space = true;
+        }
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+        if run.records.is_empty() && run.exit == Exit::Success {
+            println!("Not solved.");
+        } else {
+            for record in &run.records {
+                let verdict = answers.check(puzzle, record.part, record.answer.as_deref());
+                mismatches += usize::from(verdict.is_incorrect());
+                print_record(record, &verdict);
+            }
+            timings.push(timing_from_records(puzzle, &run.records));
+        }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        let status = Status::of(&run.records, run.exit);
+        if matches!(status, Status::Crashed(_) | Status::TimedOut(_)) {
+            eprintln!("{puzzle} {status}.");
+        }
+        statuses.push((puzzle, status));
+    };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    // NOTE: run puzzles sorted by year and day.
+    let mut puzzles: Vec<PuzzleId> = puzzles_to_run.iter().copied().collect();
+    puzzles.sort_unstable();
+
+    let executables = build_puzzles(&puzzles, is_release, timeout);
+    let run_day = |puzzle: PuzzleId, output: &Output| {
+        let executable = executables.get(&puzzle).map(PathBuf::as_path);
+        run_puzzle(puzzle, executable, is_release, is_timed, timeout, output)
+    };
 
-    if is_timed {
+    if is_parallel {
+        run_parallel(
+            &puzzles,
+            |puzzle| run_day(puzzle, &Output::buffered()),
+            |puzzle, run| {
+                print_heading(puzzle);
+                report(puzzle, run);
+            },
+        );
+    } else {
+        for puzzle in puzzles {
+            print_heading(puzzle);
+            let run = run_day(puzzle, &Output::forwarded());
+            report(puzzle, run);
+        }
+    }
+
+    print_summary(&statuses);
+
+    let timings = is_timed.then(|| {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
+        timings
+    });
+
+    MultiRun {
+        timings,
+        mismatches,
+        statuses,
     }
 }
 
-#[allow(dead_code)]
+/// Run `run` for every puzzle on as many threads as there are CPU cores.
+/// Results are passed to `report` in the order of `puzzles` as soon as all previous puzzles are done.
+fn run_parallel(
+    puzzles: &[PuzzleId],
+    run: impl Fn(PuzzleId) -> DayRun + Sync,
+    mut report: impl FnMut(PuzzleId, DayRun),
+) {
+    let jobs = thread::available_parallelism().map_or(1, NonZero::get);
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(puzzles.len()) {
+            let (next, run, sender) = (&next, &run, sender.clone());
+            scope.spawn(move || {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(&puzzle) = puzzles.get(index) else {
+                        break;
+                    };
+                    if sender.send((index, run(puzzle))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        // NOTE: the receiver stops once every worker dropped its sender.
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut next_to_report = 0;
+
+        for (index, day_run) in receiver {
+            pending.insert(index, day_run);
+            while let Some(day_run) = pending.remove(&next_to_report) {
+                report(puzzles[next_to_report], day_run);
+                next_to_report += 1;
+            }
+        }
+    });
+}
+
+/// Build the bins of the puzzles that run as a child process with a single cargo invocation,
+/// so that the days only run their executables instead of each invoking cargo.
+/// If the build fails, e.g. because one of the days does not compile, the days are built one by one when they run.
+fn build_puzzles(
+    puzzles: &[PuzzleId],
+    is_release: bool,
+    timeout: Option<Duration>,
+) -> HashMap<PuzzleId, PathBuf> {
+    let puzzles: Vec<PuzzleId> = puzzles
+        .iter()
+        .copied()
+        .filter(|puzzle| {
+            in_process_solution(*puzzle, timeout).is_none()
+                && Path::new(&puzzle.bin_path()).exists()
+                && Path::new(&puzzle.input_path()).exists()
+        })
+        .collect();
+
+    if puzzles.is_empty() {
+        return HashMap::new();
+    }
+
+    child_commands::build_solutions(&puzzles, is_release).unwrap_or_default()
+}
+
+/// Run the solution of a puzzle in-process if it is registered, or as a child process otherwise.
+/// Child processes run `executable` if it was built already.
+fn run_puzzle(
+    puzzle: PuzzleId,
+    executable: Option<&Path>,
+    is_release: bool,
+    is_timed: bool,
+    timeout: Option<Duration>,
+    output: &Output,
+) -> DayRun {
+    // skip days that have not been downloaded yet instead of reporting them as crashed.
+    if !Path::new(&puzzle.input_path()).exists() {
+        return DayRun {
+            records: vec![],
+            exit: Exit::Success,
+            output: vec![],
+        };
+    }
+
+    let (records, exit) = run_input(
+        puzzle, None, executable, is_release, is_timed, timeout, output,
+    );
+
+    DayRun {
+        records,
+        exit,
+        output: output.take_lines(),
+    }
+}
+
+/// Run the solution of a puzzle against the input at `input`, or the puzzle input if `None`.
+/// The solution runs in-process if it is registered, or as a child process otherwise,
+/// which runs `executable` if given and builds the solution bin first otherwise.
+pub fn run_input(
+    puzzle: PuzzleId,
+    input: Option<&Path>,
+    executable: Option<&Path>,
+    is_release: bool,
+    is_timed: bool,
+    timeout: Option<Duration>,
+    output: &Output,
+) -> (Vec<PartRecord>, Exit) {
+    let result = match in_process_solution(puzzle, timeout) {
+        Some(solution) => {
+            let path = input.map_or_else(|| puzzle.input_path().into(), Path::to_path_buf);
+            match fs::read_to_string(path) {
+                Ok(input) => Ok(run_in_process(solution, input, is_timed)),
+                Err(e) => {
+                    output.eprintln(format!("could not open input file: {e}"));
+                    return (vec![], Exit::Success);
+                }
+            }
+        }
+        None => child_commands::run_solution(
+            puzzle, input, executable, is_timed, is_release, timeout, output,
+        ),
+    };
+
+    result.unwrap_or_else(|e| {
+        output.eprintln(format!("could not run {puzzle}: {e}"));
+        (vec![], Exit::Crashed(None))
+    })
+}
+
+fn print_summary(statuses: &[(PuzzleId, Status)]) {
+    let count = |f: fn(&Status) -> bool| statuses.iter().filter(|(_, s)| f(s)).count();
+
+    println!(
+        "\n{ANSI_BOLD}Solved:{ANSI_RESET} {}, {ANSI_BOLD}unsolved:{ANSI_RESET} {}, \
+        {ANSI_BOLD}failed:{ANSI_RESET} {}, {ANSI_BOLD}crashed:{ANSI_RESET} {}, {ANSI_BOLD}timed out:{ANSI_RESET} {}",
+        count(|s| *s == Status::Solved),
+        count(|s| *s == Status::Unsolved),
+        count(|s| *s == Status::Failed),
+        count(|s| matches!(s, Status::Crashed(_))),
+        count(|s| matches!(s, Status::TimedOut(_))),
+    );
+}
+
+/// Run a solution that is compiled into this binary, skipping the `cargo run` invocation.
+/// Panics are caught and reported as crashes.
+///
+/// NOTE: output that the solution prints itself is not buffered.
+fn run_in_process(
+    solution: &DaySolution,
+    input: String,
+    is_timed: bool,
+) -> (Vec<PartRecord>, Exit) {
+    let run = solution.run;
+
+    match panic::catch_unwind(AssertUnwindSafe(|| run(&input, is_timed))) {
+        Ok(records) => (records, Exit::Success),
+        Err(_) => (vec![], Exit::Crashed(None)),
+    }
+}
+
+/// The registered solution of a puzzle, if it can run in-process.
+///
+/// NOTE: a thread cannot be stopped, so days with a timeout run as a child process, which is killed when it times out.
+/// Otherwise, a timed-out day would keep running in the background and skew the timings of later days.
+fn in_process_solution(
+    puzzle: PuzzleId,
+    timeout: Option<Duration>,
+) -> Option<&'static DaySolution> {
+    registry::find(puzzle).filter(|_| timeout.is_none())
+}
+
+pub fn print_record(record: &PartRecord, verdict: &Verdict) {
+    if matches!(record.part, PARSE | COMBINED) {
+        print_timing(record);
+        return;
+    }
+
+    if let Some(error) = &record.error {
+        print_failure(
+            &format!("Part {}", record.part),
+            record.puzzle,
+            record.part,
+            error,
+        );
+        return;
+    }
+
+    print_result(
+        &record.answer,
+        &format!("Part {}", record.part),
+        &format!("{}{}", verdict.marker(), format_stats(&record.stats)),
+    );
+}
+
+/// Aggregates the records of a day's parts into a [`Timing`].
+fn timing_from_records(puzzle: PuzzleId, records: &[PartRecord]) -> Timing {
+    let mut timing = Timing {
+        puzzle,
+        parse: None,
+        part_1: None,
+        part_2: None,
+        combined: None,
+        total_nanos: 0_f64,
+    };
+
+    // NOTE: unsolved parts are not benched, their duration is not meaningful.
+    // the parts of a combined solution have no samples, their call is recorded as a whole.
+    for record in records.iter().filter(|r| {
+        (matches!(r.part, PARSE | COMBINED) || r.answer.is_some()) && r.stats.samples > 0
+    }) {
+        match record.part {
+            PARSE => timing.parse = Some(record.stats),
+            COMBINED => timing.combined = Some(record.stats),
+            1 => timing.part_1 = Some(record.stats),
+            2 => timing.part_2 = Some(record.stats),
+            _ => continue,
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        {
+            timing.total_nanos += record.stats.mean.as_nanos() as f64;
+        }
+    }
+
+    timing
+}
+
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
+    /// The solution binary could not be built.
+    Build,
     IO(io::Error),
 }
 
@@ -59,47 +525,77 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "could not read output of child process."),
+            Error::Build => write!(f, "build failed."),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting the records they emit.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{Error, Exit, Output};
+    use crate::template::{PuzzleId, record::PartRecord};
     use std::{
+        collections::HashMap,
+        ffi::OsStr,
         io::{BufRead, BufReader},
-        path::Path,
+        path::{Path, PathBuf},
         process::{Command, Stdio},
         thread,
+        time::{Duration, Instant},
     };
-
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    use tinyjson::JsonValue;
+
+    /// Interval in which a running solution is checked for completion.
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+    /// Run the solution bin for a given puzzle in `--json` mode and collect its records.
+    /// The bin reads `input` if given, its puzzle input otherwise.
+    /// Runs `executable` if the bin was built already, builds it first otherwise.
+    /// If the solution runs for longer than `timeout`, it is killed.
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        input: Option<&Path>,
+        executable: Option<&Path>,
+        is_timed: bool,
+        is_release: bool,
+        timeout: Option<Duration>,
+        output: &Output,
+    ) -> Result<(Vec<PartRecord>, Exit), Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if !Path::new(&puzzle.bin_path()).exists() {
+            return Ok((vec![], Exit::Success));
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        // NOTE: the binary is built first and run directly, so that the timeout does not include
+        // compilation and killing it does not leave an orphaned process behind `cargo run`.
+        let executable = match executable {
+            Some(executable) => executable.to_path_buf(),
+            None => build_solutions(&[puzzle], is_release)?
+                .remove(&puzzle)
+                .ok_or(Error::Build)?,
+        };
 
-        if is_release {
-            args.push("--release");
-        }
+        let mut args = vec![OsStr::new("--json")];
 
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+            args.push(OsStr::new("--time"));
+        }
+
+        if let Some(input) = input {
+            args.extend([OsStr::new("--input"), input.as_os_str()]);
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward stderr and any stdout lines that are not records, e.g. debug output of a solution.
 
-        let mut cmd = Command::new("cargo")
+        let mut cmd = Command::new(executable)
             .args(&args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -108,150 +604,301 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+        let stderr_output = output.clone();
+        let stderr_thread = thread::spawn(move || {
+            stderr.lines().map_while(Result::ok).for_each(|line| {
+                stderr_output.eprintln(line);
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
+        let stdout_output = output.clone();
+        let stdout_thread = thread::spawn(move || {
+            let mut records = vec![];
+            for line in stdout.lines().map_while(Result::ok) {
+                match parse_record(&line, puzzle) {
+                    Some(record) => records.push(record),
+                    None => stdout_output.println(line),
+                }
+            }
+            records
+        });
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let started = Instant::now();
 
-        Ok(output)
-    }
+        let exit = loop {
+            if let Some(status) = cmd.try_wait()? {
+                break if status.success() {
+                    Exit::Success
+                } else {
+                    Exit::Crashed(status.code())
+                };
+            }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
+            if let Some(timeout) = timeout
+                && started.elapsed() >= timeout
+            {
+                cmd.kill()?;
+                cmd.wait()?;
+                break Exit::TimedOut(timeout);
+            }
+
+            thread::sleep(POLL_INTERVAL);
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        let records = stdout_thread.join().map_err(|_| Error::BrokenPipe)?;
+        stderr_thread.join().map_err(|_| Error::BrokenPipe)?;
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        Ok((records, exit))
+    }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+    /// Build the solution bins of `puzzles` with a single cargo invocation and return the paths of their executables.
+    pub fn build_solutions(
+        puzzles: &[PuzzleId],
+        is_release: bool,
+    ) -> Result<HashMap<PuzzleId, PathBuf>, Error> {
+        let bin_names: Vec<String> = puzzles.iter().map(PuzzleId::bin_name).collect();
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--message-format",
+            "json-render-diagnostics",
+        ];
+
+        for bin_name in &bin_names {
+            args.extend(["--bin", bin_name]);
+        }
 
-                timings.total_nanos += nanos;
-            });
+        if is_release {
+            args.push("--release");
+        }
 
-        timings
-    }
+        let output = Command::new("cargo")
+            .args(&args)
+            .stderr(Stdio::inherit())
+            .output()?;
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
+        if !output.status.success() {
+            return Err(Error::Build);
+        }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
+        let executables: HashMap<String, PathBuf> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(parse_executable)
+            .collect();
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+        Ok(puzzles
+            .iter()
+            .zip(&bin_names)
+            .filter_map(|(puzzle, bin_name)| Some((*puzzle, executables.get(bin_name)?.clone())))
+            .collect())
+    }
 
-        Some((str_timing, parsed_timing))
+    /// Parses a build message of cargo into the name of the bin it built and the path of its executable, if any.
+    pub fn parse_executable(line: &str) -> Option<(String, PathBuf)> {
+        let json = line.parse::<JsonValue>().ok()?;
+        let message = json.get::<HashMap<String, JsonValue>>()?;
+        let executable = message.get("executable")?.get::<String>()?;
+        let name = message
+            .get("target")?
+            .get::<HashMap<String, JsonValue>>()?
+            .get("name")?
+            .get::<String>()?;
+        Some((name.clone(), PathBuf::from(executable)))
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    /// Parses a line of child output into a record for `puzzle`, if it is one.
+    pub fn parse_record(line: &str, puzzle: PuzzleId) -> Option<PartRecord> {
+        line.parse::<PartRecord>()
+            .ok()
+            .filter(|r| r.puzzle == puzzle)
     }
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use std::path::PathBuf;
+
+        use super::{parse_executable, parse_record};
 
-        use crate::day;
+        use crate::{day, template::PuzzleId};
+
+        const DAY_1: PuzzleId = PuzzleId::new(2025, day!(1));
+        const DAY_2: PuzzleId = PuzzleId::new(2025, day!(2));
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+        fn parses_records() {
+            let record = parse_record(
+                r#"{"answer":"10","day":"01","max_nanos":80,"min_nanos":70,"nanos":74,"part":2,"samples":99999,"year":2025}"#,
+                DAY_1,
+            )
+            .unwrap();
+            assert_eq!(record.part, 2);
+            assert_eq!(record.answer.unwrap(), "10");
+            assert_eq!(record.stats.samples, 99999);
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn parses_executables() {
+            assert_eq!(
+                parse_executable(
+                    r#"{"reason":"compiler-artifact","target":{"name":"2025_01"},"executable":"/repo/target/release/2025_01","fresh":true}"#
+                ),
+                Some((
+                    "2025_01".into(),
+                    PathBuf::from("/repo/target/release/2025_01")
+                ))
+            );
+            assert_eq!(
+                parse_executable(
+                    r#"{"reason":"compiler-artifact","target":{"name":"advent_of_code"},"executable":null}"#
+                ),
+                None
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(parse_executable(r#"{"reason":"build-finished"}"#), None);
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+        fn ignores_other_output() {
+            assert!(parse_record("Part 1: 0 (74.13ns @ 100000 samples)", DAY_1).is_none());
+            assert!(parse_record("debug: {\"day\": 1}", DAY_1).is_none());
+            assert!(parse_record("", DAY_1).is_none());
+        }
+
+        #[test]
+        fn ignores_records_of_other_days() {
+            let line = r#"{"answer":null,"day":"02","max_nanos":0,"min_nanos":0,"nanos":0,"part":1,"samples":1,"year":2025}"#;
+            assert!(parse_record(line, DAY_1).is_none());
+            assert!(parse_record(line, DAY_2).is_some());
+            assert!(parse_record(line, PuzzleId::new(2024, day!(2))).is_none());
         }
     }
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{DayRun, Exit, Output, OutputLine, Status, run_parallel, timing_from_records};
+    use crate::{
+        day,
+        template::{Day, PuzzleId, record::PartRecord},
+    };
+
+    const DAY_1: PuzzleId = PuzzleId::new(2025, day!(1));
+
+    fn record(part: u8, answer: Option<&str>, nanos: u64) -> PartRecord {
+        PartRecord::new(
+            DAY_1,
+            part,
+            answer.map(Into::into),
+            &[Duration::from_nanos(nanos)],
+        )
+    }
+
+    #[test]
+    fn aggregates_records() {
+        let res = timing_from_records(
+            DAY_1,
+            &[
+                record(1, Some("Part 1: @ @ ( ) ms (2s @ 5 samples)"), 74),
+                record(2, Some("10"), 74_130_000),
+            ],
+        );
+        assert_eq!(res.total_nanos, 74_130_074_f64);
+        assert_eq!(res.part_1.unwrap().mean, Duration::from_nanos(74));
+        assert_eq!(res.part_2.unwrap().mean, Duration::from_nanos(74_130_000));
+    }
+
+    #[test]
+    fn handles_missing_parts() {
+        let res = timing_from_records(DAY_1, &[record(1, None, 10), record(2, None, 10)]);
+        assert_eq!(res.total_nanos, 0_f64);
+        assert!(res.part_1.is_none());
+        assert!(res.part_2.is_none());
+    }
+
+    #[test]
+    fn distinguishes_failed_from_unsolved() {
+        let failed = record(2, None, 10).with_error(Some("bad input".into()));
+
+        assert_eq!(Status::of(&[], Exit::Success), Status::Unsolved);
+        assert_eq!(
+            Status::of(&[record(1, None, 10)], Exit::Success),
+            Status::Unsolved
+        );
+        assert_eq!(
+            Status::of(&[record(1, Some("1"), 10)], Exit::Success),
+            Status::Solved
+        );
+        assert_eq!(
+            Status::of(&[record(1, Some("1"), 10), failed], Exit::Success),
+            Status::Failed
+        );
+    }
+
+    #[test]
+    fn distinguishes_crashes_and_timeouts() {
+        let timeout = Duration::from_secs(5);
+        let solved = [record(1, Some("1"), 10)];
+
+        assert_eq!(
+            Status::of(&solved, Exit::Crashed(Some(101))),
+            Status::Crashed(Some(101))
+        );
+        assert_eq!(
+            Status::of(&solved, Exit::TimedOut(timeout)),
+            Status::TimedOut(timeout)
+        );
+        assert!(Status::TimedOut(timeout).is_failure());
+        assert!(!Status::Unsolved.is_failure());
+        assert_eq!(
+            Status::Crashed(Some(101)).to_string(),
+            "crashed with exit code 101"
+        );
+    }
+
+    #[test]
+    fn reports_parallel_runs_in_order() {
+        let puzzles: Vec<PuzzleId> = (1..=6)
+            .map(|day| PuzzleId::new(2025, Day::new(day).unwrap()))
+            .collect();
+        let mut reported = vec![];
+
+        run_parallel(
+            &puzzles,
+            |puzzle| {
+                // NOTE: later days finish first.
+                let delay = 12 - u64::from(puzzle.day.into_inner()) * 2;
+                std::thread::sleep(Duration::from_millis(delay));
+                DayRun {
+                    records: vec![],
+                    exit: Exit::Success,
+                    output: vec![OutputLine::Stdout(puzzle.to_string())],
+                }
+            },
+            |puzzle, run| reported.push((puzzle, run.output)),
+        );
+
+        assert_eq!(
+            reported,
+            puzzles
+                .iter()
+                .map(|puzzle| (*puzzle, vec![OutputLine::Stdout(puzzle.to_string())]))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn buffers_output() {
+        let output = Output::buffered();
+        output.println("debug".into());
+        output.clone().eprintln("warning".into());
+        assert_eq!(
+            output.take_lines(),
+            vec![
+                OutputLine::Stdout("debug".into()),
+                OutputLine::Stderr("warning".into())
+            ]
+        );
+        assert!(output.take_lines().is_empty());
+    }
+}
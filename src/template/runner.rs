@@ -2,34 +2,409 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answers::{Answers, Verdict};
+use crate::template::input::InputSource;
+use crate::template::ocr::{answer_text, recognize};
+use crate::template::record::{COMBINED, PARSE, PartRecord, part_label};
+use crate::template::stats::Stats;
+use crate::template::submission::{Submission, Submissions};
+use crate::template::{ANSI_BOLD, ANSI_RED};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, PuzzleId, aoc};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Return types accepted from solution parts: `Option<T>` or `Result<T, E>`.
+/// A part is unsolved when it returns `None` and failed when it returns an `Err`.
+pub trait PartOutput {
+    type Answer: Display;
+
+    /// Converts the output into the answer of the part, or the message of its error.
+    fn into_answer(self) -> Result<Option<Self::Answer>, String>;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        Ok(self)
+    }
+}
+
+impl<T: Display, E: Display> PartOutput for Result<T, E> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        self.map(Some).map_err(|e| e.to_string())
+    }
+}
+
+/// Creates the record of a part from its answer or error.
+fn to_record<T: Display>(
+    puzzle: PuzzleId,
+    part: u8,
+    result: &Result<Option<T>, String>,
+    timers: &[Duration],
+) -> PartRecord {
+    let answer = result.as_ref().ok().and_then(Option::as_ref);
+    PartRecord::new(puzzle, part, answer.map(ToString::to_string), timers)
+        .with_error(result.as_ref().err().cloned())
+}
+
+/// Run the parse function of a solution and return its output, which is shared by all parts, along with its [`PartRecord`].
+pub fn run_parse<'a, T>(
+    func: impl Fn(&'a str) -> T,
+    input: &'a str,
+    puzzle: PuzzleId,
+) -> (T, PartRecord) {
+    let is_json = is_json_output();
+
+    let (parsed, timers) = run_timed(func, input, is_timed(), is_json, |_| {
+        if !is_json {
+            print!("Parse:");
+        }
+    });
+
+    let record = PartRecord::new(puzzle, PARSE, None, &timers);
+
+    if is_json {
+        println!("{}", record.to_json_line());
+    } else {
+        print_timing(&record);
+    }
+
+    (parsed, record)
+}
+
+pub fn run_part<I: Copy, O: PartOutput>(
+    func: impl Fn(I) -> O,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+) -> PartRecord {
     let part_str = format!("Part {part}");
+    let is_json = is_json_output();
+    let is_timed = is_timed();
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, timers) = run_timed(
+        |input| func(input).into_answer(),
+        input,
+        is_timed,
+        is_json,
+        |result| {
+            if !is_json {
+                print_result(
+                    &result.as_ref().ok().and_then(Option::as_ref),
+                    &part_str,
+                    "",
+                );
+            }
+        },
+    );
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let record = to_record(puzzle, part, &result, &timers);
+    report_part(&record, is_json);
+    record
+}
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+/// Run a solution that computes both parts in one call.
+/// Returns the record of the call, which is timed as a whole, followed by the records of both parts.
+pub fn run_combined<I: Copy, A: PartOutput, B: PartOutput>(
+    func: impl Fn(I) -> (A, B),
+    input: I,
+    puzzle: PuzzleId,
+) -> [PartRecord; 3] {
+    let is_json = is_json_output();
+
+    let (results, timers) = run_timed(
+        |input| {
+            let (a, b) = func(input);
+            (a.into_answer(), b.into_answer())
+        },
+        input,
+        is_timed(),
+        is_json,
+        |_| {
+            if !is_json {
+                print!("{}:", part_label(COMBINED));
+            }
+        },
+    );
+
+    let records = [
+        PartRecord::new(puzzle, COMBINED, None, &timers),
+        to_record(puzzle, 1, &results.0, &[]),
+        to_record(puzzle, 2, &results.1, &[]),
+    ];
+
+    if is_json {
+        println!("{}", records[0].to_json_line());
+    } else {
+        print_timing(&records[0]);
+    }
+
+    for record in &records[1..] {
+        report_part(record, is_json);
     }
+
+    records
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Print the record of a part, checking its answer, and submit the answer if requested.
+fn report_part(record: &PartRecord, is_json: bool) {
+    let (puzzle, part) = (record.puzzle, record.part);
+    let part_str = part_label(part);
+
+    if is_json {
+        println!("{}", record.to_json_line());
+    } else if let Some(error) = &record.error {
+        print_failure(&part_str, puzzle, part, error);
+    } else {
+        let verdict = if InputSource::from_env().is_puzzle_input() {
+            Answers::read_from_file().check(puzzle, part, record.answer.as_deref())
+        } else {
+            Verdict::Unknown
+        };
+        print_result(
+            &record.answer,
+            &part_str,
+            &format!("{}{}", verdict.marker(), format_stats(&record.stats)),
+        );
+    }
+
+    if let Some(answer) = &record.answer {
+        submit_result(answer, puzzle, part);
+    }
+}
+
+/// The result of running a named variant of a solution part.
+pub struct VariantRecord {
+    pub name: &'static str,
+    pub record: PartRecord,
+    /// Whether the variant's answer matches the answer of the main solution.
+    pub is_correct: bool,
+}
+
+/// Run a named variant of a solution part and check its answer against the `records` of the main solution.
+/// Variants are skipped in `--json` mode.
+pub fn run_variant<I: Copy, O: PartOutput>(
+    func: impl Fn(I) -> O,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+    name: &'static str,
+    records: &[PartRecord],
+) -> Option<VariantRecord> {
+    if is_json_output() {
+        return None;
+    }
+
+    let part_str = format!("Part {part} [{name}]");
+
+    let (result, timers) = run_timed(
+        |input| func(input).into_answer(),
+        input,
+        is_timed(),
+        false,
+        |result| {
+            print_result(
+                &result.as_ref().ok().and_then(Option::as_ref),
+                &part_str,
+                "",
+            );
+        },
+    );
+
+    let record = to_record(puzzle, part, &result, &timers);
+
+    let expected = records
+        .iter()
+        .find(|r| r.part == part)
+        .and_then(|r| r.answer.as_ref());
+
+    let is_correct = record.answer.is_some() && record.answer.as_ref() == expected;
+
+    let check_str = if is_correct {
+        " ✔".to_string()
+    } else {
+        format!(" ✘ (expected {})", expected.map_or("✖", String::as_str))
+    };
+
+    match &record.error {
+        Some(error) => print_failure(&part_str, puzzle, part, error),
+        None => print_result(
+            &record.answer,
+            &part_str,
+            &format!("{check_str}{}", format_stats(&record.stats)),
+        ),
+    }
+
+    Some(VariantRecord {
+        name,
+        record,
+        is_correct,
+    })
+}
+
+/// Print a comparison of all variants when benching and exit with an error if a part failed,
+/// an answer does not match the known-correct one or any variant disagrees with the main solution.
+pub fn finish(records: &[PartRecord], variants: &[VariantRecord]) {
+    if is_json_output() {
+        return;
+    }
+
+    if is_timed() && !variants.is_empty() {
+        print_variant_comparison(records, variants);
+    }
+
+    let failures = records.iter().filter(|r| r.is_failed()).count();
+
+    if failures > 0 {
+        eprintln!("{failures} part(s) failed.");
+        process::exit(1);
+    }
+
+    let answers = Answers::read_from_file();
+    // NOTE: known-correct answers only apply to the puzzle input.
+    let is_puzzle_input = InputSource::from_env().is_puzzle_input();
+    let mismatches = records
+        .iter()
+        .filter(|r| {
+            is_puzzle_input
+                && answers
+                    .check(r.puzzle, r.part, r.answer.as_deref())
+                    .is_incorrect()
+        })
+        .count();
+
+    if mismatches > 0 {
+        eprintln!("{mismatches} answer(s) do not match the known-correct answers.");
+        process::exit(1);
+    }
+
+    let incorrect: Vec<_> = variants.iter().filter(|v| !v.is_correct).collect();
+
+    if !incorrect.is_empty() {
+        eprintln!(
+            "{} variant(s) disagree with the main solution: {}",
+            incorrect.len(),
+            incorrect
+                .iter()
+                .map(|v| format!("part {} [{}]", v.record.part, v.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        process::exit(1);
+    }
+}
+
+fn print_variant_comparison(records: &[PartRecord], variants: &[VariantRecord]) {
+    println!("\n{ANSI_BOLD}Variants{ANSI_RESET}");
+    println!(
+        "{:<6}{:<16}{:<12}{:<12}Relative",
+        "Part", "Variant", "Mean", "Median"
+    );
+
+    for record in records.iter().filter(|r| matches!(r.part, 1 | 2)) {
+        let rows = std::iter::once(("default", record)).chain(
+            variants
+                .iter()
+                .filter(|v| v.record.part == record.part)
+                .map(|v| (v.name, &v.record)),
+        );
+
+        for (name, row) in rows {
+            #[allow(clippy::cast_precision_loss)]
+            let relative =
+                row.stats.mean.as_nanos() as f64 / cmp::max(record.stats.mean.as_nanos(), 1) as f64;
+
+            println!(
+                "{:<6}{:<16}{:<12}{:<12}{relative:.2}x",
+                record.part,
+                name,
+                format!("{:.1?}", row.stats.mean),
+                format!("{:.1?}", row.stats.median),
+            );
+        }
+    }
+}
+
+/// Run a solution part without printing anything and return its [`PartRecord`].
+pub fn measure_part<I: Copy, O: PartOutput>(
+    func: impl Fn(I) -> O,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+    is_timed: bool,
+) -> PartRecord {
+    let (result, timers) = run_timed(
+        |input| func(input).into_answer(),
+        input,
+        is_timed,
+        true,
+        |_| {},
+    );
+    to_record(puzzle, part, &result, &timers)
+}
+
+/// Run the parse function of a solution without printing anything and return its output along with its [`PartRecord`].
+pub fn measure_parse<'a, T>(
+    func: impl Fn(&'a str) -> T,
+    input: &'a str,
+    puzzle: PuzzleId,
+    is_timed: bool,
+) -> (T, PartRecord) {
+    let (parsed, timers) = run_timed(func, input, is_timed, true, |_| {});
+    (parsed, PartRecord::new(puzzle, PARSE, None, &timers))
+}
+
+/// Run a solution that computes both parts in one call without printing anything and return its records, see [`run_combined`].
+pub fn measure_combined<I: Copy, A: PartOutput, B: PartOutput>(
+    func: impl Fn(I) -> (A, B),
+    input: I,
+    puzzle: PuzzleId,
+    is_timed: bool,
+) -> [PartRecord; 3] {
+    let (results, timers) = run_timed(
+        |input| {
+            let (a, b) = func(input);
+            (a.into_answer(), b.into_answer())
+        },
+        input,
+        is_timed,
+        true,
+        |_| {},
+    );
+
+    [
+        PartRecord::new(puzzle, COMBINED, None, &timers),
+        to_record(puzzle, 1, &results.0, &[]),
+        to_record(puzzle, 2, &results.1, &[]),
+    ]
+}
+
+/// Whether the binary was invoked with `--time`, i.e. should bench solutions.
+fn is_timed() -> bool {
+    env::args().any(|x| x == "--time")
+}
+
+/// Whether the binary was invoked with `--json`, i.e. should emit one [`PartRecord`] per line instead of human-readable output.
+fn is_json_output() -> bool {
+    env::args().any(|x| x == "--json")
+}
+
+/// Run a solution part. The behavior differs depending on whether we are timing the solution:
+///  1. by default, the function is executed once.
+///  2. with `is_timed`, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// Returns the result along with the execution time of every sample.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    is_timed: bool,
+    is_quiet: bool,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Vec<Duration>) {
     let timer = Instant::now();
     let result = {
         #[cfg(feature = "dhat-heap")]
@@ -41,62 +416,82 @@ fn run_timed<I: Copy, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let timers = if is_timed {
+        bench(func, input, &base_time, is_quiet)
     } else {
-        (base_time, 1)
+        vec![base_time]
     };
 
-    (result, run.0, run.1)
+    (result, timers)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
-
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    is_quiet: bool,
+) -> Vec<Duration> {
+    if !is_quiet {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
 
     let bench_iterations =
         (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
+    // warm up caches and branch predictors, these samples are discarded.
+    let warmup_iterations = cmp::max(bench_iterations / 10, 1);
+
     let mut timers: Vec<Duration> = vec![];
 
-    for _ in 0..bench_iterations {
+    for i in 0..warmup_iterations + bench_iterations {
         let timer = Instant::now();
         black_box(func(black_box(input)));
-        timers.push(timer.elapsed());
+        if i >= warmup_iterations {
+            timers.push(timer.elapsed());
+        }
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    timers
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
+pub(crate) fn format_stats(stats: &Stats) -> String {
+    // NOTE: only the parts of a combined solution are recorded without samples.
+    if stats.samples == 0 {
+        return " (combined)".into();
+    }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+    if stats.samples == 1 {
+        return format!(" ({:.1?})", stats.mean);
     }
+
+    let mut str = format!(
+        " ({:.1?} @ {} samples; median {:.1?}, σ {:.1?}, p99 {:.1?}",
+        stats.mean, stats.samples, stats.median, stats.std_dev, stats.p99
+    );
+
+    if stats.outliers > 0 {
+        str.push_str(&format!(", {} outliers", stats.outliers));
+    }
+
+    str.push(')');
+    str
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+pub(crate) fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
         Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
+            let result = result.to_string();
+            if result.contains('\n') {
+                // NOTE: letters drawn by the solution are shown next to the art, as they will be submitted.
+                let str = match recognize(&result) {
+                    Some(letters) => {
+                        format!("{part}: {ANSI_BOLD}{letters}{ANSI_RESET} ▼ {duration_str}")
+                    }
+                    None => format!("{part}: ▼ {duration_str}"),
+                };
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -125,18 +520,44 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
+/// Print the execution time of a record without an answer, i.e. of the parse phase or a combined solution.
+pub(crate) fn print_timing(record: &PartRecord) {
+    print!("\r");
+    println!(
+        "{}:{}",
+        part_label(record.part),
+        format_stats(&record.stats)
+    );
+}
+
+/// Print the error of a failed part along with the puzzle it belongs to.
+pub(crate) fn print_failure(part_str: &str, puzzle: PuzzleId, part: u8, error: &str) {
+    print!("\r");
+    println!("{part_str}: ✖             ");
+    eprintln!("{ANSI_RED}error:{ANSI_RESET} {puzzle} part {part} failed: {error}");
+}
+
+/// Store a correct answer, so that later runs can be checked against it.
+fn record_answer(puzzle: PuzzleId, part: u8, answer: String) {
+    let mut answers = Answers::read_from_file();
+    answers.insert(puzzle, part, answer);
+
+    match answers.store_file() {
+        Ok(()) => println!("Recorded correct answer for {puzzle} part {part}."),
+        Err(e) => eprintln!("Failed to record correct answer: {e}"),
+    }
+}
+
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. aoc-cli is installed or the `client` feature is enabled.
+///
+/// Answers that were checked before or lie outside of known too-high / too-low bounds are not submitted.
+fn submit_result<T: Display>(result: &T, puzzle: PuzzleId, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -152,16 +573,65 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
+    }
+
+    if !InputSource::from_env().is_puzzle_input() {
+        eprintln!("Not submitting: only answers for the puzzle input can be submitted.");
+        process::exit(1);
     }
 
-    if aoc_cli::check().is_err() {
+    if let Err(e) = aoc::check() {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+
+    let answer = answer_text(&result.to_string()).into_owned();
+
+    if answer.contains('\n') {
         eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            "Not submitting: the answer spans multiple lines and its letters could not be recognised."
         );
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let mut submissions = match Submissions::read_from_file() {
+        Ok(submissions) => submissions,
+        Err(e) => {
+            eprintln!(
+                "Not submitting: failed to read the submission ledger `data/submissions.json`: {e}"
+            );
+            process::exit(1);
+        }
+    };
+
+    if let Err(refusal) = submissions.check(puzzle, part, &answer) {
+        eprintln!("Not submitting \"{answer}\": {refusal}.");
+        process::exit(1);
+    }
+
+    println!("Submitting result...");
+    let response = match aoc::submit(puzzle, part, &answer) {
+        Ok(response) => response,
+        Err(e) => {
+            eprintln!("failed to submit result: {e}");
+            return;
+        }
+    };
+
+    let Some(submission) = Submission::parse(&response) else {
+        eprintln!("Could not interpret the response to the submission.");
+        return;
+    };
+
+    println!("Submission is {submission}.");
+
+    if submission == Submission::Correct {
+        record_answer(puzzle, part, answer.clone());
+    }
+
+    submissions.push(puzzle, part, answer, submission);
+    if let Err(e) = submissions.store_file() {
+        eprintln!("Failed to store submission: {e}");
+    }
 }
@@ -0,0 +1,239 @@
+/// Selection of days passed to the `all`, `time` and `solve` commands, e.g. `1-5,7`, `..7`, `unsolved` or `slowest:3`.
+use std::{collections::HashSet, str::FromStr};
+
+use crate::template::{
+    Day, PuzzleId, all_days, answers::Answers, calendar_length, timings::Timings,
+};
+
+/// A single term of a [`DaySelection`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Selector {
+    /// Days from the first to the last day, both inclusive. Open ends extend to the start or end of the calendar.
+    Range(Option<Day>, Option<Day>),
+    /// Days without a known-correct answer for every part.
+    Unsolved,
+    /// The `n` days with the slowest stored timings.
+    Slowest(usize),
+}
+
+/// A comma-separated list of days, ranges and filters.
+///
+/// - `7`: a single day.
+/// - `1-5`: days 1 to 5, both inclusive.
+/// - `..7` / `20..`: all days up to / starting from a day.
+/// - `unsolved`: days without a known-correct answer for every part in `data/answers.json`.
+/// - `slowest:3`: the three days with the slowest timings in `data/timings.json`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DaySelection(Vec<Selector>);
+
+impl DaySelection {
+    /// Resolves the selection to puzzles of `year`.
+    /// Errors if a day is not part of the year's calendar.
+    pub fn resolve(
+        &self,
+        year: u16,
+        answers: &Answers,
+        timings: &Timings,
+    ) -> Result<HashSet<PuzzleId>, String> {
+        let mut puzzles = HashSet::new();
+        let last_day = calendar_length(year);
+
+        for selector in &self.0 {
+            match *selector {
+                Selector::Range(from, to) => {
+                    if let Some(day) = from.into_iter().chain(to).find(|day| *day > last_day) {
+                        return Err(format!(
+                            "day {day} is not part of the {year} calendar, which has {last_day} days."
+                        ));
+                    }
+
+                    puzzles.extend(
+                        all_days(year)
+                            .filter(|day| from.is_none_or(|from| *day >= from))
+                            .filter(|day| to.is_none_or(|to| *day <= to))
+                            .map(|day| PuzzleId::new(year, day)),
+                    );
+                }
+                Selector::Unsolved => {
+                    puzzles.extend(
+                        all_days(year)
+                            .map(|day| PuzzleId::new(year, day))
+                            .filter(|puzzle| !is_solved(*puzzle, answers)),
+                    );
+                }
+                Selector::Slowest(n) => {
+                    let mut year_timings: Vec<_> = timings
+                        .data
+                        .iter()
+                        .filter(|t| t.puzzle.year == year)
+                        .collect();
+                    year_timings.sort_unstable_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+                    puzzles.extend(year_timings.into_iter().take(n).map(|t| t.puzzle));
+                }
+            }
+        }
+
+        Ok(puzzles)
+    }
+}
+
+/// Whether every part of a puzzle has a known-correct answer.
+/// The last day of a calendar only has one part.
+fn is_solved(puzzle: PuzzleId, answers: &Answers) -> bool {
+    let parts: &[u8] = if puzzle.day == calendar_length(puzzle.year) {
+        &[1]
+    } else {
+        &[1, 2]
+    };
+    parts
+        .iter()
+        .all(|part| answers.get(puzzle, *part).is_some())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let selectors = s
+            .split(',')
+            .map(str::trim)
+            .map(Selector::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(DaySelection(selectors))
+    }
+}
+
+impl FromStr for Selector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |s: &str| -> Result<Option<Day>, String> {
+            if s.is_empty() {
+                return Ok(None);
+            }
+            Day::from_str(s)
+                .map(Some)
+                .map_err(|e| format!("invalid day \"{s}\": {e}"))
+        };
+
+        if s == "unsolved" {
+            return Ok(Selector::Unsolved);
+        }
+
+        if let Some(n) = s.strip_prefix("slowest:") {
+            return n
+                .parse()
+                .map(Selector::Slowest)
+                .map_err(|_| format!("invalid number of days \"{n}\""));
+        }
+
+        let range = s.split_once("..").or_else(|| s.split_once('-'));
+
+        match range {
+            Some((from, to)) => Ok(Selector::Range(parse_day(from)?, parse_day(to)?)),
+            None => {
+                let day = parse_day(s)?.ok_or("expected a day selection")?;
+                Ok(Selector::Range(Some(day), Some(day)))
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{collections::HashSet, time::Duration};
+
+    use super::DaySelection;
+    use crate::template::{
+        Day, PuzzleId,
+        answers::Answers,
+        stats::Stats,
+        timings::{Timing, Timings},
+    };
+
+    fn days(year: u16, days: &[u8]) -> HashSet<PuzzleId> {
+        days.iter()
+            .map(|day| PuzzleId::new(year, Day::new(*day).unwrap()))
+            .collect()
+    }
+
+    fn resolve(selection: &str, year: u16) -> Result<HashSet<PuzzleId>, String> {
+        selection
+            .parse::<DaySelection>()?
+            .resolve(year, &Answers::default(), &Timings::default())
+    }
+
+    #[test]
+    fn selects_days_and_ranges() {
+        assert_eq!(resolve("7", 2025), Ok(days(2025, &[7])));
+        assert_eq!(resolve("1-3,7", 2025), Ok(days(2025, &[1, 2, 3, 7])));
+        assert_eq!(resolve("1, 3 ,7", 2025), Ok(days(2025, &[1, 3, 7])));
+        assert_eq!(resolve("..3", 2025), Ok(days(2025, &[1, 2, 3])));
+        assert_eq!(resolve("10..", 2025), Ok(days(2025, &[10, 11, 12])));
+        assert_eq!(resolve("23..", 2024), Ok(days(2024, &[23, 24, 25])));
+    }
+
+    #[test]
+    fn rejects_invalid_selections() {
+        assert!(resolve("0", 2025).is_err());
+        assert!(resolve("1-x", 2025).is_err());
+        assert!(resolve("slowest:x", 2025).is_err());
+        assert!(resolve("", 2025).is_err());
+        assert_eq!(
+            resolve("10-13", 2025),
+            Err("day 13 is not part of the 2025 calendar, which has 12 days.".into())
+        );
+    }
+
+    #[test]
+    fn selects_unsolved_days() {
+        let mut answers = Answers::default();
+        answers.insert(PuzzleId::new(2025, Day::new(1).unwrap()), 1, "1".into());
+        answers.insert(PuzzleId::new(2025, Day::new(1).unwrap()), 2, "2".into());
+        answers.insert(PuzzleId::new(2025, Day::new(2).unwrap()), 1, "1".into());
+        answers.insert(PuzzleId::new(2025, Day::new(12).unwrap()), 1, "1".into());
+
+        let selection: DaySelection = "..3,unsolved".parse().unwrap();
+        assert_eq!(
+            selection.resolve(2025, &answers, &Timings::default()),
+            Ok(days(2025, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]))
+        );
+
+        let selection: DaySelection = "unsolved".parse().unwrap();
+        assert_eq!(
+            selection.resolve(2025, &answers, &Timings::default()),
+            Ok(days(2025, &[2, 3, 4, 5, 6, 7, 8, 9, 10, 11]))
+        );
+    }
+
+    #[test]
+    fn selects_slowest_days() {
+        let timing = |year: u16, day: u8, millis: u64| Timing {
+            puzzle: PuzzleId::new(year, Day::new(day).unwrap()),
+            parse: None,
+            part_1: Some(Stats::from_mean(Duration::from_millis(millis))),
+            part_2: None,
+            combined: None,
+            #[allow(clippy::cast_precision_loss)]
+            total_nanos: (millis * 1_000_000) as f64,
+        };
+        let timings = Timings {
+            data: vec![
+                timing(2025, 1, 10),
+                timing(2025, 2, 30),
+                timing(2025, 3, 20),
+                timing(2024, 4, 100),
+            ],
+        };
+
+        let selection: DaySelection = "slowest:2".parse().unwrap();
+        assert_eq!(
+            selection.resolve(2025, &Answers::default(), &timings),
+            Ok(days(2025, &[2, 3]))
+        );
+    }
+}
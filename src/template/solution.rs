@@ -0,0 +1,102 @@
+/// Library interface of a day's solution, implemented by the `solution!` macro.
+/// Allows calling a day from integration tests, benches and tools instead of running its binary.
+use crate::template::PuzzleId;
+use crate::template::runner::PartOutput;
+
+/// The answer of a part as text, or the error message of a failed part.
+/// Used as the output of parts whose return type the `solution!` macro cannot name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextAnswer(pub Result<Option<String>, String>);
+
+impl TextAnswer {
+    /// Converts the output of a part into its answer as text.
+    pub fn of(output: impl PartOutput) -> Self {
+        Self(output.into_answer().map(|a| a.map(|a| a.to_string())))
+    }
+}
+
+impl PartOutput for TextAnswer {
+    type Answer = String;
+
+    fn into_answer(self) -> Result<Option<String>, String> {
+        self.0
+    }
+}
+
+/// A day's solution: an optional parse phase followed by both parts.
+///
+/// Parts return `Option<T>` or `Result<T, E>`, see [`PartOutput`] for their answer types.
+/// Parts that are not implemented return no answer.
+///
+/// The `solution!` macro cannot name the return types of a day's functions, so its parts return a [`TextAnswer`]
+/// unless the types are passed to the macro, e.g. `answers: (Option<usize>, Option<u64>)`.
+/// Days declared with `#[aoc::solution]` return the types of their functions.
+///
+/// With the `registry` feature, the solutions in `src/bin` are compiled into the library,
+/// e.g. `advent_of_code::days::day_2025_07::Solver`.
+pub trait Solution {
+    /// The puzzle solved by this solution.
+    const PUZZLE: PuzzleId;
+
+    /// The output of [`Solution::parse`], which both parts take a reference to.
+    type Parsed<'a>;
+
+    /// The output of [`Solution::part_one`].
+    type Part1: PartOutput;
+
+    /// The output of [`Solution::part_two`].
+    type Part2: PartOutput;
+
+    /// Parses the input once for both parts.
+    fn parse(input: &str) -> Self::Parsed<'_>;
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Self::Part1;
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Self::Part2;
+
+    /// Parses `input` and solves both parts.
+    fn solve(input: &str) -> (Self::Part1, Self::Part2) {
+        let parsed = Self::parse(input);
+        (Self::part_one(&parsed), Self::part_two(&parsed))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Solution, TextAnswer};
+    use crate::{day, template::PuzzleId};
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const PUZZLE: PuzzleId = PuzzleId::new(2025, day!(1));
+
+        type Parsed<'a> = Vec<&'a str>;
+        type Part1 = Option<usize>;
+        type Part2 = Result<usize, String>;
+
+        fn parse(input: &str) -> Vec<&str> {
+            input.lines().collect()
+        }
+
+        fn part_one(lines: &Vec<&str>) -> Option<usize> {
+            Some(lines.len())
+        }
+
+        fn part_two(_lines: &Vec<&str>) -> Result<usize, String> {
+            Err("not solved yet".into())
+        }
+    }
+
+    #[test]
+    fn solves_both_parts() {
+        let (part_one, part_two) = Lines::solve("a\nb\nc");
+        assert_eq!(part_one, Some(3));
+        assert_eq!(
+            TextAnswer::of(part_two),
+            TextAnswer(Err("not solved yet".into()))
+        );
+    }
+}
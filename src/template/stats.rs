@@ -0,0 +1,242 @@
+/// Summary statistics of benchmark samples.
+use std::{collections::HashMap, time::Duration};
+use tinyjson::JsonValue;
+
+/// Minimum distance of the outlier fences from the quartiles, as a fraction of the median.
+const MIN_FENCE_WIDTH: f64 = 0.05;
+
+/// Maximum fraction of the samples that are rejected as outliers.
+const MAX_OUTLIER_FRACTION: f64 = 0.1;
+
+/// Statistics over the execution times of a benched solution part.
+///
+/// Samples outside of the [Tukey fences](https://en.wikipedia.org/wiki/Outlier#Tukey's_fences)
+/// (1.5 × IQR below the first or above the third quartile) are counted as outliers and
+/// excluded from the mean and standard deviation. All other values consider every sample.
+///
+/// The fences are at least 5% of the median away from the quartiles, so that fast parts whose
+/// samples mostly share the same value don't reject every sample that differs by a nanosecond.
+/// At most 10% of the samples are rejected, the ones furthest from the median first.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    pub samples: u128,
+    pub outliers: u128,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+}
+
+impl Stats {
+    /// Computes statistics over the execution times of each sample.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn from_samples(timers: &[Duration]) -> Self {
+        if timers.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted: Vec<f64> = timers.iter().map(|t| t.as_nanos() as f64).collect();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let median = quantile(&sorted, 0.5);
+        let q1 = quantile(&sorted, 0.25);
+        let q3 = quantile(&sorted, 0.75);
+        let width = (1.5 * (q3 - q1)).max(MIN_FENCE_WIDTH * median).max(1_f64);
+        let (low, high) = (q1 - width, q3 + width);
+
+        // NOTE: outliers are at either end of the sorted samples, reject the furthest from the median
+        // until both ends are within the fences or the maximum number of outliers is reached.
+        let max_outliers = (sorted.len() as f64 * MAX_OUTLIER_FRACTION) as usize;
+        let (mut start, mut end) = (0, sorted.len());
+        while start + sorted.len() - end < max_outliers {
+            let (first, last) = (sorted[start], sorted[end - 1]);
+            let is_low = first < low;
+            let is_high = last > high;
+            if is_low && (!is_high || median - first > last - median) {
+                start += 1;
+            } else if is_high {
+                end -= 1;
+            } else {
+                break;
+            }
+        }
+        let inliers = &sorted[start..end];
+
+        let mean = inliers.iter().sum::<f64>() / inliers.len() as f64;
+        let variance =
+            inliers.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / inliers.len() as f64;
+
+        let nanos = |x: f64| Duration::from_nanos(x.round() as u64);
+
+        Self {
+            samples: sorted.len() as u128,
+            outliers: (sorted.len() - inliers.len()) as u128,
+            mean: nanos(mean),
+            median: nanos(median),
+            min: nanos(sorted[0]),
+            max: nanos(sorted[sorted.len() - 1]),
+            std_dev: nanos(variance.sqrt()),
+            p95: nanos(quantile(&sorted, 0.95)),
+            p99: nanos(quantile(&sorted, 0.99)),
+        }
+    }
+
+    /// Creates statistics from a known mean when no samples are available,
+    /// e.g. for timings stored by earlier versions.
+    pub fn from_mean(mean: Duration) -> Self {
+        Self {
+            samples: 0,
+            outliers: 0,
+            mean,
+            median: mean,
+            min: mean,
+            max: mean,
+            std_dev: Duration::ZERO,
+            p95: mean,
+            p99: mean,
+        }
+    }
+
+    /// Writes the statistics to the keys of a JSON object.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn write_json(&self, map: &mut HashMap<String, JsonValue>) {
+        map.insert("samples".into(), JsonValue::Number(self.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(self.outliers as f64));
+
+        for (key, value) in self.durations() {
+            map.insert(key.into(), JsonValue::Number(value.as_nanos() as f64));
+        }
+    }
+
+    /// Reads statistics from the keys of a JSON object.
+    /// Keys other than `nanos` and `samples` are optional and default to the mean.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn read_json(map: &HashMap<String, JsonValue>) -> Result<Self, String> {
+        let number = |key: &str| map.get(key).and_then(|v| v.get::<f64>()).copied();
+        let duration = |key: &str| number(key).map(|x| Duration::from_nanos(x as u64));
+
+        let mean = duration("nanos").ok_or("Expected `nanos` to be a number.")?;
+        let samples = number("samples").ok_or("Expected `samples` to be a number.")? as u128;
+
+        Ok(Self {
+            samples,
+            outliers: number("outliers").map_or(0, |x| x as u128),
+            mean,
+            median: duration("median_nanos").unwrap_or(mean),
+            min: duration("min_nanos").unwrap_or(mean),
+            max: duration("max_nanos").unwrap_or(mean),
+            std_dev: duration("std_dev_nanos").unwrap_or_default(),
+            p95: duration("p95_nanos").unwrap_or(mean),
+            p99: duration("p99_nanos").unwrap_or(mean),
+        })
+    }
+
+    fn durations(&self) -> [(&'static str, Duration); 7] {
+        [
+            ("nanos", self.mean),
+            ("median_nanos", self.median),
+            ("min_nanos", self.min),
+            ("max_nanos", self.max),
+            ("std_dev_nanos", self.std_dev),
+            ("p95_nanos", self.p95),
+            ("p99_nanos", self.p99),
+        ]
+    }
+}
+
+/// Linearly interpolated quantile of sorted values.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let (lower, upper) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (pos - pos.floor())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{collections::HashMap, time::Duration};
+
+    use super::Stats;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = Stats::from_samples(&nanos(&[42]));
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.mean, Duration::from_nanos(42));
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.p99, Duration::from_nanos(42));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+
+    #[test]
+    fn computes_order_statistics() {
+        let stats = Stats::from_samples(&nanos(&[40, 10, 30, 20]));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(40));
+        assert_eq!(stats.median, Duration::from_nanos(25));
+        assert_eq!(stats.mean, Duration::from_nanos(25));
+        assert_eq!(stats.std_dev, Duration::from_nanos(11));
+    }
+
+    #[test]
+    fn rejects_outliers_from_mean() {
+        let mut samples = vec![100; 99];
+        samples.push(1_000_000);
+        let stats = Stats::from_samples(&nanos(&samples));
+        assert_eq!(stats.samples, 100);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.mean, Duration::from_nanos(100));
+        assert_eq!(stats.median, Duration::from_nanos(100));
+        assert_eq!(stats.max, Duration::from_nanos(1_000_000));
+    }
+
+    #[test]
+    fn keeps_samples_close_to_identical_ones() {
+        let mut samples = vec![200; 9_000];
+        samples.extend([199; 500]);
+        samples.extend([201; 500]);
+        samples.push(5_000);
+        let stats = Stats::from_samples(&nanos(&samples));
+        assert_eq!(stats.samples, 10_001);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.mean, Duration::from_nanos(200));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+
+    #[test]
+    fn caps_rejected_samples() {
+        let mut samples = vec![100; 70];
+        samples.extend([1_000; 15]);
+        samples.extend([10_000; 15]);
+        let stats = Stats::from_samples(&nanos(&samples));
+        assert_eq!(stats.samples, 100);
+        assert_eq!(stats.outliers, 10);
+        assert_eq!(stats.max, Duration::from_nanos(10_000));
+    }
+
+    #[test]
+    fn roundtrips_through_json() {
+        let stats = Stats::from_samples(&nanos(&[5, 7, 9, 11, 100]));
+        let mut map = HashMap::new();
+        stats.write_json(&mut map);
+        assert_eq!(Stats::read_json(&map).unwrap(), stats);
+    }
+}
@@ -0,0 +1,463 @@
+/// Interprets the responses to submitted answers and keeps a ledger of them in `data/submissions.json`,
+/// so that answers which are known to be wrong are not sent to the server again.
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+    time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    PuzzleId,
+    puzzle::{from_nested_json, to_nested_json},
+};
+
+static SUBMISSIONS_FILE_PATH: &str = "./data/submissions.json";
+
+/// Whether an incorrect answer was too high or too low.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// The server's verdict on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+    },
+    /// The part was solved before, the answer was not checked.
+    AlreadySolved,
+    /// An answer was submitted too recently, the answer was not checked.
+    Wait {
+        duration: Duration,
+    },
+}
+
+impl Submission {
+    /// Parses the text of the server's response to a submission.
+    pub fn parse(response: &str) -> Option<Self> {
+        // NOTE: the server wraps long lines and sometimes uses double spaces between sentences.
+        let response = response.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if response.contains("That's the right answer") {
+            Some(Submission::Correct)
+        } else if response.contains("That's not the right answer") {
+            let hint = if response.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if response.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Some(Submission::Incorrect { hint })
+        } else if response.contains("Did you already complete it?") {
+            Some(Submission::AlreadySolved)
+        } else if response.contains("You gave an answer too recently") {
+            Some(Submission::Wait {
+                duration: parse_wait_duration(&response).unwrap_or_default(),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Submission::Correct => write!(f, "correct"),
+            Submission::Incorrect { hint: None } => write!(f, "incorrect"),
+            Submission::Incorrect {
+                hint: Some(Hint::TooHigh),
+            } => write!(f, "incorrect (too high)"),
+            Submission::Incorrect {
+                hint: Some(Hint::TooLow),
+            } => write!(f, "incorrect (too low)"),
+            Submission::AlreadySolved => write!(f, "already solved"),
+            Submission::Wait { duration } => write!(f, "rate-limited, wait {duration:?}"),
+        }
+    }
+}
+
+/// Parses durations such as `1m 3s` from "You have 1m 3s left to wait."
+fn parse_wait_duration(response: &str) -> Option<Duration> {
+    let (_, rest) = response.split_once("You have ")?;
+    let (duration, _) = rest.split_once(" left to wait")?;
+
+    duration
+        .split_whitespace()
+        .try_fold(Duration::ZERO, |acc, x| {
+            let seconds = match x.split_at(x.len().checked_sub(1)?) {
+                (n, "s") => n.parse::<u64>().ok()?,
+                (n, "m") => n.parse::<u64>().ok()? * 60,
+                (n, "h") => n.parse::<u64>().ok()? * 3600,
+                _ => return None,
+            };
+            Some(acc + Duration::from_secs(seconds))
+        })
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An answer that was submitted, together with the server's verdict.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubmittedAnswer {
+    pub answer: String,
+    pub submission: Submission,
+}
+
+/// All submitted answers per puzzle and part.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Submissions {
+    data: BTreeMap<(PuzzleId, u8), Vec<SubmittedAnswer>>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(SUBMISSIONS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns no submissions.
+    /// Fails if the file exists but cannot be read or parsed, since answers known to be wrong would be sent again.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(SUBMISSIONS_FILE_PATH) {
+            Ok(s) => Submissions::from_str(&s),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Returns the submitted answers of a part in the order they were submitted.
+    pub fn get(&self, puzzle: PuzzleId, part: u8) -> &[SubmittedAnswer] {
+        self.data.get(&(puzzle, part)).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn push(&mut self, puzzle: PuzzleId, part: u8, answer: String, submission: Submission) {
+        self.data
+            .entry((puzzle, part))
+            .or_default()
+            .push(SubmittedAnswer { answer, submission });
+    }
+
+    /// Checks whether `answer` is worth submitting, given the earlier submissions of a part.
+    pub fn check(&self, puzzle: PuzzleId, part: u8, answer: &str) -> Result<(), Refusal> {
+        if answer.trim().is_empty() {
+            return Err(Refusal::Empty);
+        }
+
+        let submitted = self.get(puzzle, part);
+
+        // NOTE: rate-limited answers and answers to solved parts were not checked by the server.
+        if let Some(previous) = submitted.iter().find(|x| {
+            x.answer == answer
+                && matches!(
+                    x.submission,
+                    Submission::Correct | Submission::Incorrect { .. }
+                )
+        }) {
+            return Err(Refusal::Repeated(previous.submission));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |hint: Hint| {
+            submitted.iter().filter_map(move |x| match x.submission {
+                Submission::Incorrect { hint: Some(h) } if h == hint => {
+                    x.answer.parse::<i128>().ok()
+                }
+                _ => None,
+            })
+        };
+
+        if let Some(high) = bound(Hint::TooHigh).min().filter(|high| value >= *high) {
+            return Err(Refusal::TooHigh { bound: high });
+        }
+
+        if let Some(low) = bound(Hint::TooLow).max().filter(|low| value <= *low) {
+            return Err(Refusal::TooLow { bound: low });
+        }
+
+        Ok(())
+    }
+}
+
+/// The reason an answer is not submitted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    Empty,
+    /// The answer was submitted and checked before.
+    Repeated(Submission),
+    /// The answer is not below `bound`, which was too high.
+    TooHigh {
+        bound: i128,
+    },
+    /// The answer is not above `bound`, which was too low.
+    TooLow {
+        bound: i128,
+    },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Empty => write!(f, "the answer is empty"),
+            Refusal::Repeated(submission) => {
+                write!(f, "it was submitted before and is {submission}")
+            }
+            Refusal::TooHigh { bound } => write!(f, "{bound} was already too high"),
+            Refusal::TooLow { bound } => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&SubmittedAnswer> for JsonValue {
+    fn from(value: &SubmittedAnswer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+
+        let result = match value.submission {
+            Submission::Correct => "correct",
+            Submission::Incorrect { hint } => {
+                if let Some(hint) = hint {
+                    let hint = match hint {
+                        Hint::TooHigh => "too_high",
+                        Hint::TooLow => "too_low",
+                    };
+                    map.insert("hint".into(), JsonValue::String(hint.into()));
+                }
+                "incorrect"
+            }
+            Submission::AlreadySolved => "already_solved",
+            Submission::Wait { duration } => {
+                #[allow(clippy::cast_precision_loss)]
+                map.insert(
+                    "wait_secs".into(),
+                    JsonValue::Number(duration.as_secs() as f64),
+                );
+                "wait"
+            }
+        };
+        map.insert("result".into(), JsonValue::String(result.into()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for SubmittedAnswer {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let string = |key: &str| json.get(key).and_then(|v| v.get::<String>());
+
+        let answer = string("answer").ok_or("Expected submission.answer to be a string.")?;
+
+        let submission = match string("result").map(String::as_str) {
+            Some("correct") => Submission::Correct,
+            Some("incorrect") => Submission::Incorrect {
+                hint: match string("hint").map(String::as_str) {
+                    Some("too_high") => Some(Hint::TooHigh),
+                    Some("too_low") => Some(Hint::TooLow),
+                    _ => None,
+                },
+            },
+            Some("already_solved") => Submission::AlreadySolved,
+            Some("wait") => Submission::Wait {
+                duration: Duration::from_secs(
+                    json.get("wait_secs")
+                        .and_then(|v| v.get::<f64>())
+                        .map_or(0, |x| *x as u64),
+                ),
+            },
+            _ => return Err("Expected submission.result to be a known result.".into()),
+        };
+
+        Ok(SubmittedAnswer {
+            answer: answer.clone(),
+            submission,
+        })
+    }
+}
+
+impl From<&Submissions> for JsonValue {
+    fn from(value: &Submissions) -> Self {
+        to_nested_json(value.data.iter().map(|((puzzle, part), answers)| {
+            (
+                *puzzle,
+                *part,
+                JsonValue::Array(answers.iter().map(JsonValue::from).collect()),
+            )
+        }))
+    }
+}
+
+impl TryFrom<&JsonValue> for Submissions {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let mut submissions = Submissions::default();
+
+        for (puzzle, part, answers) in from_nested_json(value, "submissions")? {
+            let answers = answers
+                .get::<Vec<JsonValue>>()
+                .ok_or("Expected submissions.year.day.part to be an array.")?
+                .iter()
+                .map(SubmittedAnswer::try_from)
+                .collect::<Result<_, _>>()?;
+
+            submissions.data.insert((puzzle, part), answers);
+        }
+
+        Ok(submissions)
+    }
+}
+
+impl FromStr for Submissions {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON file."))?;
+        Submissions::try_from(&json)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Hint, Refusal, Submission, Submissions};
+    use crate::{day, template::PuzzleId};
+
+    const DAY_1: PuzzleId = PuzzleId::new(2025, day!(1));
+
+    #[test]
+    fn parses_responses() {
+        assert_eq!(
+            Submission::parse(
+                "That's the right answer! You are one gold star closer to decorating the North Pole."
+            ),
+            Some(Submission::Correct)
+        );
+        assert_eq!(
+            Submission::parse(
+                "That's not the right answer; your answer is too\nhigh.  If you're stuck, ..."
+            ),
+            Some(Submission::Incorrect {
+                hint: Some(Hint::TooHigh)
+            })
+        );
+        assert_eq!(
+            Submission::parse("That's not the right answer.  If you're stuck, ..."),
+            Some(Submission::Incorrect { hint: None })
+        );
+        assert_eq!(
+            Submission::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Some(Submission::AlreadySolved)
+        );
+        assert_eq!(
+            Submission::parse(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 3s left to wait."
+            ),
+            Some(Submission::Wait {
+                duration: Duration::from_secs(63)
+            })
+        );
+        assert_eq!(Submission::parse("404 Not Found"), None);
+    }
+
+    #[test]
+    fn refuses_repeated_answers() {
+        let mut submissions = Submissions::default();
+        submissions.push(DAY_1, 1, "41".into(), Submission::Incorrect { hint: None });
+        submissions.push(
+            DAY_1,
+            1,
+            "43".into(),
+            Submission::Wait {
+                duration: Duration::from_secs(30),
+            },
+        );
+
+        assert_eq!(
+            submissions.check(DAY_1, 1, "41"),
+            Err(Refusal::Repeated(Submission::Incorrect { hint: None }))
+        );
+        assert_eq!(submissions.check(DAY_1, 1, "43"), Ok(()));
+        assert_eq!(submissions.check(DAY_1, 2, "41"), Ok(()));
+        assert_eq!(submissions.check(DAY_1, 2, " "), Err(Refusal::Empty));
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let mut submissions = Submissions::default();
+        let incorrect = |hint| Submission::Incorrect { hint: Some(hint) };
+        submissions.push(DAY_1, 1, "100".into(), incorrect(Hint::TooHigh));
+        submissions.push(DAY_1, 1, "80".into(), incorrect(Hint::TooHigh));
+        submissions.push(DAY_1, 1, "-5".into(), incorrect(Hint::TooLow));
+
+        assert_eq!(
+            submissions.check(DAY_1, 1, "90"),
+            Err(Refusal::TooHigh { bound: 80 })
+        );
+        assert_eq!(
+            submissions.check(DAY_1, 1, "-10"),
+            Err(Refusal::TooLow { bound: -5 })
+        );
+        assert_eq!(submissions.check(DAY_1, 1, "79"), Ok(()));
+        assert_eq!(submissions.check(DAY_1, 1, "ABCDEF"), Ok(()));
+    }
+
+    #[test]
+    fn roundtrips_through_json() {
+        let mut submissions = Submissions::default();
+        submissions.push(
+            DAY_1,
+            1,
+            "41".into(),
+            Submission::Incorrect {
+                hint: Some(Hint::TooLow),
+            },
+        );
+        submissions.push(
+            DAY_1,
+            1,
+            "43".into(),
+            Submission::Wait {
+                duration: Duration::from_secs(30),
+            },
+        );
+        submissions.push(DAY_1, 1, "42".into(), Submission::Correct);
+        submissions.push(
+            PuzzleId::new(2024, day!(2)),
+            2,
+            "7".into(),
+            Submission::AlreadySolved,
+        );
+
+        let json = tinyjson::JsonValue::from(&submissions).stringify().unwrap();
+        assert_eq!(json.parse::<Submissions>().unwrap(), submissions);
+    }
+}
@@ -1,16 +1,24 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{
+    Day, PuzzleId, default_year,
+    record::{COMBINED, PARSE},
+    stats::Stats,
+};
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
-    pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub puzzle: PuzzleId,
+    /// The parse phase of solutions that share a parsed input between parts.
+    pub parse: Option<Stats>,
+    pub part_1: Option<Stats>,
+    pub part_2: Option<Stats>,
+    /// Solutions that compute both parts in one call are timed as a whole.
+    pub combined: Option<Stats>,
     pub total_nanos: f64,
 }
 
@@ -46,12 +54,12 @@ impl Timings {
         }
 
         for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
+            if !data.iter().any(|t| t.puzzle == timing.puzzle) {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.puzzle);
         Timings { data }
     }
 
@@ -60,10 +68,56 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
-            .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+    /// Returns the timing of `puzzle`, if present.
+    pub fn get(&self, puzzle: PuzzleId) -> Option<&Timing> {
+        self.data.iter().find(|t| t.puzzle == puzzle)
+    }
+
+    pub fn is_day_complete(&self, puzzle: PuzzleId) -> bool {
+        self.data.iter().any(|t| {
+            t.puzzle == puzzle
+                && ((t.part_1.is_some() && t.part_2.is_some()) || t.combined.is_some())
+        })
+    }
+}
+
+impl Timing {
+    /// Returns the statistics of a part, or of the parse phase for [`PARSE`] and of a combined solution for [`COMBINED`], if it was benched.
+    pub fn part(&self, part: u8) -> Option<&Stats> {
+        match part {
+            PARSE => self.parse.as_ref(),
+            COMBINED => self.combined.as_ref(),
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Mean execution time of a part in nanoseconds.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        self.part(part).map(|stats| stats.mean.as_nanos() as f64)
+    }
+}
+
+/// Formats nanoseconds like the `Debug` representation of a [`Duration`], e.g. `74.1ns`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos as u64))
+}
+
+fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
+    s.split(postfix).next()?.parse().ok()
+}
+
+/// Parses the `Debug` representation of a [`Duration`] into nanoseconds.
+fn parse_duration_str(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    match s.trim() {
+        s if s.contains("ns") => parse_to_float(s, "ns"),
+        s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+        s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
     }
 }
 
@@ -87,7 +141,14 @@ impl TryFrom<String> for Timings {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        Timings::try_from(&json)
+    }
+}
 
+impl TryFrom<&JsonValue> for Timings {
+    type Error = String;
+
+    fn try_from(json: &JsonValue) -> Result<Self, Self::Error> {
         let json_data = json
             .get::<HashMap<String, JsonValue>>()
             .ok_or("expected JSON document to be an object.")?
@@ -111,27 +172,34 @@ impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
-        map.insert("day".into(), JsonValue::String(value.day.to_string()));
-        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
-
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
-
         map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            "year".into(),
+            JsonValue::Number(f64::from(value.puzzle.year)),
         );
-
         map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            "day".into(),
+            JsonValue::String(value.puzzle.day.to_string()),
         );
+        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
+
+        for (key, stats) in [
+            ("parse", value.parse),
+            ("part_1", value.part_1),
+            ("part_2", value.part_2),
+            ("combined", value.combined),
+        ] {
+            map.insert(
+                key.into(),
+                match stats {
+                    Some(stats) => {
+                        let mut stats_map = HashMap::new();
+                        stats.write_json(&mut stats_map);
+                        JsonValue::Object(stats_map)
+                    }
+                    None => JsonValue::Null,
+                },
+            );
+        }
 
         JsonValue::Object(map)
     }
@@ -140,6 +208,7 @@ impl From<&Timing> for JsonValue {
 impl TryFrom<&JsonValue> for Timing {
     type Error = String;
 
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
         let json = value
             .get::<HashMap<String, JsonValue>>()
@@ -151,35 +220,78 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
-
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+        // NOTE: timings stored by earlier versions belong to the year set in `AOC_YEAR`.
+        let year = match json.get("year") {
+            Some(v) => v.get::<f64>().map(|year| *year as u16),
+            None => default_year(),
+        }
+        .ok_or("Expected timing.year to be a number.")?;
 
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: timings stored by earlier versions have no parse phase or combined solutions.
+        let read_optional_part = |key: &str| match json.get(key) {
+            Some(_) => read_part(json, key),
+            None => Ok(None),
+        };
+
         Ok(Timing {
-            day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            puzzle: PuzzleId::new(year, day),
+            parse: read_optional_part("parse")?,
+            part_1: read_part(json, "part_1")?,
+            part_2: read_part(json, "part_2")?,
+            combined: read_optional_part("combined")?,
             total_nanos,
         })
     }
 }
 
+/// Reads the statistics of a part, which is either `null` or an object.
+/// Timings stored by earlier versions hold a formatted duration string instead,
+/// optionally alongside the statistics in `part_N_stats`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn read_part(json: &HashMap<String, JsonValue>, key: &str) -> Result<Option<Stats>, String> {
+    let value = json
+        .get(key)
+        .ok_or_else(|| format!("Expected timing.{key} to be null, object or string."))?;
+
+    if value.is_null() {
+        return Ok(None);
+    }
+
+    if let Some(duration_str) = value.get::<String>() {
+        if let Some(stats) = json
+            .get(&format!("{key}_stats"))
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+        {
+            return Stats::read_json(stats).map(Some);
+        }
+
+        return parse_duration_str(duration_str)
+            .map(|nanos| Some(Stats::from_mean(Duration::from_nanos(nanos as u64))))
+            .ok_or_else(|| format!("Expected timing.{key} to be a valid duration."));
+    }
+
+    value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or_else(|| format!("Expected timing.{key} to be null, object or string."))
+        .and_then(|map| Stats::read_json(map).map_err(|e| format!("timing.{key}: {e}")))
+        .map(Some)
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use std::time::Duration;
+
+    use crate::{
+        day,
+        template::{PuzzleId, stats::Stats},
+    };
 
     use super::{Timing, Timings};
 
@@ -187,21 +299,27 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    puzzle: PuzzleId::new(2025, day!(1)),
+                    parse: None,
+                    part_1: Some(Stats::from_mean(Duration::from_millis(10))),
+                    part_2: Some(Stats::from_mean(Duration::from_millis(20))),
+                    combined: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    puzzle: PuzzleId::new(2025, day!(2)),
+                    parse: None,
+                    part_1: Some(Stats::from_mean(Duration::from_millis(30))),
+                    part_2: Some(Stats::from_mean(Duration::from_millis(40))),
+                    combined: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
+                    puzzle: PuzzleId::new(2025, day!(4)),
+                    parse: None,
+                    part_1: Some(Stats::from_mean(Duration::from_millis(40))),
                     part_2: None,
+                    combined: None,
                     total_nanos: 4e+10,
                 },
             ],
@@ -209,18 +327,69 @@ mod tests {
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{PuzzleId, timings::Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "data": [{ "year": 2024, "day": "01", "part_1": { "nanos": 1000000, "samples": 100 }, "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
-            assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(timing.puzzle, PuzzleId::new(2024, day!(1)));
+            assert_eq!(timing.part_1.unwrap().mean, Duration::from_millis(1));
+            assert_eq!(timing.part_1.unwrap().samples, 100);
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.total_nanos, 1_000_000_f64);
+        }
+
+        #[test]
+        fn handles_parse_timings() {
+            let json = r#"{ "data": [{ "year": 2025, "day": "05", "parse": { "nanos": 2000, "samples": 10 }, "part_1": { "nanos": 1000, "samples": 10 }, "part_2": null, "total_nanos": 3000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.parse.unwrap().mean, Duration::from_micros(2));
+            assert_eq!(timing.part_nanos(0), Some(2000_f64));
+
+            let json = r#"{ "data": [{ "year": 2025, "day": "05", "part_1": null, "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.first().unwrap().parse, None);
+        }
+
+        #[test]
+        fn handles_formatted_durations() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "74.13ns", "part_2": "1.5µs", "total_nanos": 1574.13 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1.unwrap().mean, Duration::from_nanos(74));
+            assert_eq!(timing.part_1.unwrap().samples, 0);
+            assert_eq!(
+                timing.part_2.unwrap().mean,
+                Duration::from_micros(1) * 3 / 2
+            );
+        }
+
+        #[test]
+        fn handles_formatted_durations_with_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "nanos": 1000000, "median_nanos": 900000, "samples": 100, "outliers": 2 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1.unwrap();
+            assert_eq!(stats.samples, 100);
+            assert_eq!(stats.outliers, 2);
+            assert_eq!(stats.median.as_nanos(), 900_000);
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_durations() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "fast", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
         }
 
         #[test]
@@ -253,7 +422,7 @@ mod tests {
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
-            let value = JsonValue::try_from(timings).unwrap();
+            let value = JsonValue::from(timings);
             assert_eq!(
                 value
                     .get::<HashMap<String, JsonValue>>()
@@ -269,58 +438,100 @@ mod tests {
     }
 
     mod is_day_complete {
+        use std::time::Duration;
+
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                PuzzleId,
+                stats::Stats,
+                timings::{Timing, Timings},
+            },
         };
 
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    puzzle: PuzzleId::new(2025, day!(1)),
+                    parse: None,
+                    part_1: Some(Stats::from_mean(Duration::from_millis(1))),
+                    part_2: Some(Stats::from_mean(Duration::from_millis(2))),
+                    combined: None,
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert!(timings.is_day_complete(PuzzleId::new(2025, day!(1))));
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
+                    puzzle: PuzzleId::new(2025, day!(1)),
+                    parse: None,
+                    part_1: Some(Stats::from_mean(Duration::from_millis(1))),
                     part_2: None,
+                    combined: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert!(!timings.is_day_complete(PuzzleId::new(2025, day!(1))));
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: PuzzleId::new(2025, day!(1)),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    combined: None,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert!(!timings.is_day_complete(PuzzleId::new(2025, day!(1))));
+        }
+    }
+
+    mod part_nanos {
+        use crate::{
+            day,
+            template::{
+                PuzzleId,
+                timings::{Timings, format_nanos},
+            },
+        };
+
+        use super::get_mock_timings;
+
+        #[test]
+        fn returns_mean_nanos() {
+            let timings: Timings = get_mock_timings();
+            let timing = timings.get(PuzzleId::new(2025, day!(4))).unwrap();
+            assert_eq!(timing.part_nanos(1), Some(40_000_000_f64));
+            assert_eq!(timing.part_nanos(2), None);
+            assert_eq!(timing.part_nanos(3), None);
+        }
+
+        #[test]
+        fn formats_nanos() {
+            assert_eq!(format_nanos(74.13), "74.0ns");
+            assert_eq!(format_nanos(117_200_000_f64), "117.2ms");
         }
     }
 
     mod merge {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                PuzzleId,
+                timings::{Timing, Timings},
+            },
         };
 
         use super::get_mock_timings;
@@ -330,18 +541,20 @@ mod tests {
             let timings = get_mock_timings();
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(3),
+                    puzzle: PuzzleId::new(2025, day!(3)),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    combined: None,
                     total_nanos: 0_f64,
                 }],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[2].day, day!(3));
-            assert_eq!(merged.data[3].day, day!(4));
+            assert_eq!(merged.data[0].puzzle.day, day!(1));
+            assert_eq!(merged.data[1].puzzle.day, day!(2));
+            assert_eq!(merged.data[2].puzzle.day, day!(3));
+            assert_eq!(merged.data[3].puzzle.day, day!(4));
         }
 
         #[test]
@@ -350,19 +563,21 @@ mod tests {
 
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(2),
+                    puzzle: PuzzleId::new(2025, day!(2)),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    combined: None,
                     total_nanos: 0_f64,
                 }],
             };
             let merged = timings.merge(&other);
 
             assert_eq!(merged.data.len(), 3);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[0].puzzle.day, day!(1));
+            assert_eq!(merged.data[1].puzzle.day, day!(2));
             assert_eq!(merged.data[1].total_nanos, 0_f64);
-            assert_eq!(merged.data[2].day, day!(4));
+            assert_eq!(merged.data[2].puzzle.day, day!(4));
         }
 
         #[test]
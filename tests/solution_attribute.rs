@@ -0,0 +1,73 @@
+//! A day declared with `#[aoc::solution]`, using a parse function, both parts and a variant.
+//! The expansion includes the `examples_match_manifest` test, which checks the parts against `data/2025/examples/03.manifest`.
+use advent_of_code::aoc;
+use advent_of_code::template::Solution;
+
+pub struct Bank(Vec<u8>);
+
+pub struct Banks(Vec<Bank>);
+
+impl Bank {
+    fn highest_joltage(&self, batteries: usize) -> usize {
+        let mut joltage = 0;
+        let mut start = 0;
+        for keep in (0..batteries).rev() {
+            let candidates = &self.0[start..self.0.len() - keep];
+            let (index, battery) = candidates
+                .iter()
+                .enumerate()
+                .rev()
+                .max_by_key(|(_, battery)| **battery)
+                .unwrap();
+            joltage = joltage * 10 + *battery as usize;
+            start += index + 1;
+        }
+        joltage
+    }
+}
+
+#[aoc::solution(2025, 3)]
+mod solution {
+    use super::{Bank, Banks};
+
+    #[aoc::parse]
+    fn banks(input: &str) -> Banks {
+        Banks(
+            input
+                .lines()
+                .map(|line| Bank(line.bytes().map(|b| b - b'0').collect()))
+                .collect(),
+        )
+    }
+
+    #[aoc::part(1)]
+    fn highest_joltage(banks: &Banks) -> Option<usize> {
+        Some(banks.0.iter().map(|bank| bank.highest_joltage(2)).sum())
+    }
+
+    #[aoc::variant(1, "pairs")]
+    fn highest_joltage_pairs(banks: &Banks) -> Option<usize> {
+        let highest = |bank: &Bank| {
+            (0..bank.0.len())
+                .flat_map(|i| (i + 1..bank.0.len()).map(move |j| (i, j)))
+                .map(|(i, j)| bank.0[i] as usize * 10 + bank.0[j] as usize)
+                .max()
+                .unwrap_or_default()
+        };
+        Some(banks.0.iter().map(highest).sum())
+    }
+
+    #[aoc::part(2)]
+    fn highest_joltage_of_twelve(banks: &Banks) -> Result<usize, String> {
+        Ok(banks.0.iter().map(|bank| bank.highest_joltage(12)).sum())
+    }
+}
+
+#[test]
+fn solver_returns_typed_answers() {
+    let input = advent_of_code::template::read_file("examples", PUZZLE);
+    let banks = Solver::parse(&input);
+
+    assert_eq!(Solver::part_one(&banks), Some(357));
+    assert_eq!(Solver::part_two(&banks), Ok(3121910778619));
+}
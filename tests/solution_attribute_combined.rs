@@ -0,0 +1,36 @@
+//! A day declared with `#[aoc::solution]`, solving both parts in one pass with `#[aoc::combined]`.
+use advent_of_code::aoc;
+use advent_of_code::template::Solution;
+
+#[aoc::solution(2025, 3)]
+mod solution {
+    #[aoc::combined]
+    fn highest_joltages(input: &str) -> (Option<usize>, Option<u64>) {
+        let highest = |bank: &[u8], batteries: usize| {
+            let mut joltage = 0;
+            let mut start = 0;
+            for keep in (0..batteries).rev() {
+                let candidates = &bank[start..bank.len() - keep];
+                let battery = *candidates.iter().max().unwrap();
+                joltage = joltage * 10 + (battery - b'0') as usize;
+                start += candidates.iter().position(|b| *b == battery).unwrap() + 1;
+            }
+            joltage
+        };
+        let (one, two) = input
+            .lines()
+            .map(|line| (highest(line.as_bytes(), 2), highest(line.as_bytes(), 12)))
+            .fold((0, 0), |(one, two), (a, b)| (one + a, two + b));
+        Some(one).zip(Some(two as u64)).unzip()
+    }
+}
+
+#[test]
+fn solver_returns_typed_answers() {
+    let input = advent_of_code::template::read_file("examples", PUZZLE);
+
+    assert_eq!(
+        Solver::solve(Solver::parse(&input)),
+        (Some(357), Some(3121910778619))
+    );
+}
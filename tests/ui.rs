@@ -0,0 +1,5 @@
+//! Checks the diagnostics of `#[aoc::solution]` for mistakes in the declared functions.
+#[test]
+fn solution_attribute_errors() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
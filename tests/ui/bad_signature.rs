@@ -0,0 +1,9 @@
+use advent_of_code::aoc;
+
+#[aoc::solution(2025, 3)]
+mod solution {
+    #[aoc::part(1)]
+    fn length(input: &str, offset: usize) -> Option<usize> {
+        Some(input.len() + offset)
+    }
+}
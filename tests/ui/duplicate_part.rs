@@ -0,0 +1,14 @@
+use advent_of_code::aoc;
+
+#[aoc::solution(2025, 3)]
+mod solution {
+    #[aoc::part(1)]
+    fn first(input: &str) -> Option<usize> {
+        Some(input.len())
+    }
+
+    #[aoc::part(1)]
+    fn second(input: &str) -> Option<usize> {
+        Some(input.lines().count())
+    }
+}
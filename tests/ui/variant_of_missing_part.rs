@@ -0,0 +1,14 @@
+use advent_of_code::aoc;
+
+#[aoc::solution(2025, 3)]
+mod solution {
+    #[aoc::part(1)]
+    fn length(input: &str) -> Option<usize> {
+        Some(input.len())
+    }
+
+    #[aoc::variant(2, "lines")]
+    fn lines(input: &str) -> Option<usize> {
+        Some(input.lines().count())
+    }
+}